@@ -0,0 +1,245 @@
+use chrono::Utc;
+
+use crate::error::{check_count, check_positive_input, QuantError};
+use crate::instruments::vanilla_option::VanillaOption;
+use crate::math::optimization::{levenberg_marquardt, LevenbergMarquardtSettings};
+use crate::pricing::black_scholes::{black_scholes_price_with_dividends, implied_volatility};
+use crate::pricing::check_expiry;
+use crate::pricing::fourier::characteristic_function_price;
+use crate::processes::heston_process::HestonProcess;
+use crate::processes::Simulate;
+
+const PARAMETER_BOUNDS: [(f64, f64); 5] = [
+    (1e-3, 20.0), // kappa
+    (1e-4, 2.0), // theta
+    (1e-3, 5.0), // sigma
+    (-0.999, 0.999), // rho
+    (1e-4, 2.0), // v0
+];
+
+// With the Feller condition enforced, sigma is replaced by its ratio to the Feller bound sqrt(2 kappa theta)
+const FELLER_RATIO_BOUNDS: (f64, f64) = (1e-3, 1.0);
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MarketQuote {
+    Price(f64),
+    ImpliedVolatility(f64),
+}
+
+pub struct CalibrationQuote {
+    pub option: VanillaOption,
+    pub quote: MarketQuote,
+    pub weight: f64,
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct HestonCalibrationSettings {
+    pub enforce_feller: bool, // Restrict the fit to 2 kappa theta >= sigma^2 so that the variance stays strictly positive
+    pub optimizer: LevenbergMarquardtSettings,
+}
+
+pub struct HestonCalibrationResult {
+    pub process: HestonProcess,
+    pub price_errors: Vec<f64>, // Model minus market price for each quote
+    pub implied_volatility_errors: Vec<Option<f64>>, // Model minus market implied volatility, when the model price is invertible
+    pub rmse: f64, // Root mean squared weighted price error
+    pub max_absolute_error: f64,
+    pub iterations: usize,
+    pub converged: bool,
+    pub feller_satisfied: bool,
+}
+
+impl CalibrationQuote {
+    pub fn new(option: VanillaOption, quote: MarketQuote, weight: f64) -> Self {
+        CalibrationQuote { option, quote, weight }
+    }
+
+    // Implied volatilities are quoted on the spot net of the dividends paid before expiry
+    fn market_price(&self, process: &HestonProcess) -> f64 {
+        match self.quote {
+            MarketQuote::Price(price) => price,
+            MarketQuote::ImpliedVolatility(sigma) => black_scholes_price_with_dividends(&self.option, process.s0, process.r, sigma, &process.dividends).amount,
+        }
+    }
+
+    fn validate(&self) -> Result<(), QuantError> {
+        check_expiry(self.option.exercise_datetime)?;
+        check_positive_input("weight", self.weight)?;
+        match self.quote {
+            MarketQuote::Price(price) => check_positive_input("price", price),
+            MarketQuote::ImpliedVolatility(sigma) => check_positive_input("implied volatility", sigma),
+        }
+    }
+
+    fn escrowed_spot(&self, process: &HestonProcess) -> f64 {
+        process.dividends.escrowed_spot(process.s0, process.r, Utc::now(), self.option.exercise_datetime)
+    }
+}
+
+fn process_from_parameters(parameters: &[f64], initial_guess: &HestonProcess, enforce_feller: bool) -> HestonProcess {
+    let (kappa, theta) = (parameters[0], parameters[1]);
+    let sigma = if enforce_feller { parameters[2] * (2.0 * kappa * theta).sqrt() } else { parameters[2] };
+
+    HestonProcess::new(initial_guess.s0, parameters[4], initial_guess.r, kappa, theta, sigma, parameters[3], initial_guess.t)
+        .with_dividends(initial_guess.dividends.clone())
+}
+
+// Fits kappa, theta, sigma, rho and v0 to the quotes, keeping the spot, rate and horizon of `initial_guess`
+pub fn calibrate_heston(quotes: &[CalibrationQuote], initial_guess: &HestonProcess, settings: HestonCalibrationSettings) -> Result<HestonCalibrationResult, QuantError> {
+    check_count("quotes", quotes.len())?;
+    for quote in quotes {
+        quote.validate()?;
+    }
+    initial_guess.validate()?;
+
+    let market_prices: Vec<f64> = quotes.iter().map(|quote| quote.market_price(initial_guess)).collect();
+
+    let residuals = |parameters: &[f64]| {
+        let process = process_from_parameters(parameters, initial_guess, settings.enforce_feller);
+        quotes.iter()
+            .zip(market_prices.iter())
+            .map(|(quote, market_price)| quote.weight.sqrt() * (characteristic_function_price(&quote.option, &process).amount - market_price))
+            .collect::<Vec<f64>>()
+    };
+
+    let mut bounds = PARAMETER_BOUNDS;
+    let mut initial_parameters = [initial_guess.kappa, initial_guess.theta, initial_guess.sigma, initial_guess.rho, initial_guess.v0];
+    if settings.enforce_feller {
+        bounds[2] = FELLER_RATIO_BOUNDS;
+        initial_parameters[2] = initial_guess.sigma / (2.0 * initial_guess.kappa * initial_guess.theta).sqrt();
+    }
+
+    let result = levenberg_marquardt(residuals, &initial_parameters, &bounds, settings.optimizer);
+    let process = process_from_parameters(&result.parameters, initial_guess, settings.enforce_feller);
+
+    let model_prices: Vec<f64> = quotes.iter().map(|quote| characteristic_function_price(&quote.option, &process).amount).collect();
+    let price_errors: Vec<f64> = model_prices.iter().zip(market_prices.iter()).map(|(model, market)| model - market).collect();
+    let implied_volatility_errors = quotes.iter()
+        .zip(model_prices.iter().zip(market_prices.iter()))
+        .map(|(quote, (&model, &market))| {
+            let escrowed_s0 = quote.escrowed_spot(&process);
+            let model_volatility = implied_volatility(&quote.option, model, escrowed_s0, process.r)?;
+            let market_volatility = match quote.quote {
+                MarketQuote::ImpliedVolatility(sigma) => sigma,
+                MarketQuote::Price(_) => implied_volatility(&quote.option, market, escrowed_s0, process.r)?,
+            };
+            Some(model_volatility - market_volatility)
+        })
+        .collect();
+
+    let total_weight: f64 = quotes.iter().map(|quote| quote.weight).sum();
+    let weighted_squared_error: f64 = quotes.iter().zip(price_errors.iter()).map(|(quote, error)| quote.weight * error.powi(2)).sum();
+
+    Ok(HestonCalibrationResult {
+        rmse: (weighted_squared_error / total_weight).sqrt(),
+        max_absolute_error: price_errors.iter().fold(0.0, |max, error| error.abs().max(max)),
+        price_errors,
+        implied_volatility_errors,
+        iterations: result.iterations,
+        converged: result.converged,
+        feller_satisfied: process.feller_condition_satisfied(),
+        process,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::cashflows::{Currency, Dividend, DividendSchedule};
+    use crate::instruments::OptionType;
+    use crate::pricing::black_scholes::black_scholes_price;
+
+    use super::*;
+
+    fn create_quotes(process: &HestonProcess) -> Vec<CalibrationQuote> {
+        let mut quotes = Vec::new();
+        for days in [180, 730] {
+            for strike in [85.0, 100.0, 115.0] {
+                let exercise_datetime = Utc::now() + Duration::days(days);
+                let option = VanillaOption::new(strike, exercise_datetime, exercise_datetime, OptionType::Call, Currency::USD);
+                let price = characteristic_function_price(&option, process).amount;
+                let escrowed_s0 = process.dividends.escrowed_spot(process.s0, process.r, Utc::now(), exercise_datetime);
+                let sigma = implied_volatility(&option, price, escrowed_s0, process.r).unwrap();
+                quotes.push(CalibrationQuote::new(option, MarketQuote::ImpliedVolatility(sigma), 1.0));
+            }
+        }
+        quotes
+    }
+
+    #[test]
+    fn test_heston_price_converges_to_black_scholes() {
+        // With vanishing vol of vol and v0 = theta the model collapses to Black-Scholes
+        let process = HestonProcess::new(100.0, 0.04, 0.03, 2.0, 0.04, 1e-3, 0.0, 1.0);
+        let exercise_datetime = Utc::now() + Duration::days(365);
+        let option = VanillaOption::new(105.0, exercise_datetime, exercise_datetime, OptionType::Put, Currency::USD);
+
+        let price = characteristic_function_price(&option, &process).amount;
+        let expected_price = black_scholes_price(&option, 100.0, 0.03, 0.2).amount;
+        assert!((price - expected_price).abs() < 1e-3, "Price {} not close to Black-Scholes {}", price, expected_price);
+    }
+
+    fn create_dividends() -> DividendSchedule {
+        DividendSchedule::new(vec![Dividend::cash(2.0, Utc::now() + Duration::days(90)), Dividend::proportional(0.02, Utc::now() + Duration::days(400))])
+    }
+
+    #[test]
+    fn test_heston_price_with_dividends_converges_to_black_scholes() {
+        let process = HestonProcess::new(100.0, 0.04, 0.03, 2.0, 0.04, 1e-3, 0.0, 2.0).with_dividends(create_dividends());
+
+        for days in [60, 180, 730] {
+            let exercise_datetime = Utc::now() + Duration::days(days);
+            let option = VanillaOption::new(100.0, exercise_datetime, exercise_datetime, OptionType::Call, Currency::USD);
+
+            let price = characteristic_function_price(&option, &process).amount;
+            let expected_price = black_scholes_price_with_dividends(&option, 100.0, 0.03, 0.2, &process.dividends).amount;
+            assert!((price - expected_price).abs() < 1e-3, "Price {} not close to Black-Scholes {} after {} days", price, expected_price, days);
+        }
+    }
+
+    #[test]
+    fn test_calibration_recovers_parameters() {
+        for (dividends, t) in [(DividendSchedule::default(), 1.0), (create_dividends(), 2.0)] {
+            let true_process = HestonProcess::new(100.0, 0.05, 0.02, 1.5, 0.06, 0.4, -0.6, t).with_dividends(dividends.clone());
+            let quotes = create_quotes(&true_process);
+            let initial_guess = HestonProcess::new(100.0, 0.03, 0.02, 1.0, 0.04, 0.3, -0.3, t).with_dividends(dividends);
+
+            let result = calibrate_heston(&quotes, &initial_guess, HestonCalibrationSettings::default()).unwrap();
+
+            assert!(result.rmse < 1e-3, "Calibration error {} too large", result.rmse);
+            assert!(result.implied_volatility_errors.iter().all(|error| error.unwrap().abs() < 1e-4));
+            assert!((result.process.rho - true_process.rho).abs() < 0.05, "Fitted rho {}", result.process.rho);
+            assert!((result.process.v0 - true_process.v0).abs() < 0.005, "Fitted v0 {}", result.process.v0);
+        }
+    }
+
+    #[test]
+    fn test_invalid_quotes() {
+        let initial_guess = HestonProcess::new(100.0, 0.03, 0.02, 1.0, 0.04, 0.3, -0.3, 1.0);
+        let settings = HestonCalibrationSettings::default();
+        assert_eq!(calibrate_heston(&[], &initial_guess, settings).err(), Some(QuantError::EmptyInput { name: "quotes" }));
+
+        let exercise_datetime = Utc::now() + Duration::days(180);
+        let option = || VanillaOption::new(100.0, exercise_datetime, exercise_datetime, OptionType::Call, Currency::USD);
+        let negative_weight = [CalibrationQuote::new(option(), MarketQuote::Price(7.0), -1.0)];
+        assert!(matches!(calibrate_heston(&negative_weight, &initial_guess, settings), Err(QuantError::InvalidInput { name: "weight", .. })));
+        let nan_price = [CalibrationQuote::new(option(), MarketQuote::Price(f64::NAN), 1.0)];
+        assert!(matches!(calibrate_heston(&nan_price, &initial_guess, settings), Err(QuantError::InvalidInput { name: "price", .. })));
+        let zero_volatility = [CalibrationQuote::new(option(), MarketQuote::ImpliedVolatility(0.0), 1.0)];
+        assert!(matches!(calibrate_heston(&zero_volatility, &initial_guess, settings), Err(QuantError::InvalidInput { name: "implied volatility", .. })));
+    }
+
+    #[test]
+    fn test_calibration_enforces_feller_condition() {
+        let true_process = HestonProcess::new(100.0, 0.04, 0.02, 1.0, 0.04, 0.35, -0.7, 1.0);
+        let quotes = create_quotes(&true_process);
+        let initial_guess = HestonProcess::new(100.0, 0.04, 0.02, 1.0, 0.05, 0.3, -0.5, 1.0);
+        let settings = HestonCalibrationSettings { enforce_feller: true, ..HestonCalibrationSettings::default() };
+
+        let result = calibrate_heston(&quotes, &initial_guess, settings).unwrap();
+
+        assert!(!true_process.feller_condition_satisfied());
+        assert!(result.converged);
+        assert!(2.0 * result.process.kappa * result.process.theta >= result.process.sigma.powi(2) - 1e-12);
+    }
+}
@@ -0,0 +1 @@
+pub mod heston_calibration;
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+
+use crate::cashflows::{CashFlow, Currency};
+use crate::curves::{Compounding, DiscountCurve, InterestRate};
+use crate::error::QuantError;
+use crate::math::root_finding::{bracket_root, brent};
+use crate::time::DayCount;
+
+// Rates of return scanned for a sign change of the value, from close to a total loss up to a hundredfold gain
+const RATE_GRID: [f64; 13] = [-0.999, -0.9, -0.5, -0.2, 0.0, 0.1, 0.25, 0.5, 1.0, 2.0, 5.0, 10.0, 100.0];
+
+fn common_currency(cashflows: &[CashFlow]) -> Result<Currency, QuantError> {
+    let currency = cashflows.first().ok_or(QuantError::EmptyInput { name: "cashflows" })?.currency;
+    match cashflows.iter().find(|cashflow| cashflow.currency != currency) {
+        Some(cashflow) => Err(QuantError::CurrencyMismatch { left: currency, right: cashflow.currency }),
+        None => Ok(currency),
+    }
+}
+
+// Value at `valuation_datetime` of single currency cashflows discounted at `interest_rate`
+pub fn npv(cashflows: &[CashFlow], valuation_datetime: DateTime<Utc>, interest_rate: &InterestRate) -> Result<CashFlow, QuantError> {
+    let currency = common_currency(cashflows)?;
+    let value = cashflows.iter()
+        .map(|cashflow| cashflow.amount * interest_rate.discount_factor(valuation_datetime, cashflow.settlement_datetime))
+        .sum();
+    Ok(CashFlow::new(value, currency, valuation_datetime))
+}
+
+// Value at the reference date of the curve
+pub fn npv_with_curve(cashflows: &[CashFlow], discount_curve: &dyn DiscountCurve) -> Result<CashFlow, QuantError> {
+    let currency = common_currency(cashflows)?;
+    let value = cashflows.iter().map(|cashflow| discount_curve.present_value(cashflow).amount).sum();
+    Ok(CashFlow::new(value, currency, discount_curve.reference_datetime()))
+}
+
+fn rate_of_return(times: &[f64], amounts: &[f64], name: &'static str) -> Result<f64, QuantError> {
+    let value = |rate: f64| times.iter().zip(amounts.iter()).map(|(t, amount)| amount * (1.0 + rate).powf(-t)).sum::<f64>();
+    let (lower, upper) = bracket_root(value, &RATE_GRID).ok_or(QuantError::RootNotFound { name })?;
+    brent(value, lower, upper, 1e-14, 200).ok_or(QuantError::RootNotFound { name })
+}
+
+// Internal rate of return per period of amounts paid at the end of consecutive equal periods, the first one now
+pub fn irr(amounts: &[f64]) -> Result<f64, QuantError> {
+    if amounts.is_empty() {
+        return Err(QuantError::EmptyInput { name: "amounts" });
+    }
+    let times: Vec<f64> = (0..amounts.len()).map(|i| i as f64).collect();
+    rate_of_return(&times, amounts, "internal rate of return")
+}
+
+// Annually compounded Act/365F internal rate of return of cashflows on arbitrary dates, as in spreadsheet XIRR
+pub fn xirr(cashflows: &[CashFlow]) -> Result<f64, QuantError> {
+    common_currency(cashflows)?;
+    let first_datetime = cashflows.iter().map(|cashflow| cashflow.settlement_datetime).min().unwrap();
+    let times: Vec<f64> = cashflows.iter().map(|cashflow| DayCount::Actual365Fixed.year_fraction(first_datetime, cashflow.settlement_datetime)).collect();
+    let amounts: Vec<f64> = cashflows.iter().map(|cashflow| cashflow.amount).collect();
+    rate_of_return(&times, &amounts, "internal rate of return")
+}
+
+struct YieldSensitivities {
+    value: f64,
+    time_weighted_value: f64,
+    first_derivative: f64, // -dP/dy
+    second_derivative: f64, // d2P/dy2
+}
+
+fn yield_sensitivities(cashflows: &[CashFlow], valuation_datetime: DateTime<Utc>, interest_rate: &InterestRate) -> Result<YieldSensitivities, QuantError> {
+    common_currency(cashflows)?;
+    let mut sensitivities = YieldSensitivities { value: 0.0, time_weighted_value: 0.0, first_derivative: 0.0, second_derivative: 0.0 };
+
+    for cashflow in cashflows.iter() {
+        let t = interest_rate.day_count.year_fraction(valuation_datetime, cashflow.settlement_datetime);
+        let discount_factor = interest_rate.discount_factor_for(t);
+        let (first, second) = match interest_rate.compounding {
+            Compounding::Simple => (t * discount_factor, 2.0 * (t * discount_factor).powi(2)),
+            Compounding::Periodic(frequency) => {
+                let growth = 1.0 + interest_rate.rate / frequency as f64;
+                (t / growth, t * (t + 1.0 / frequency as f64) / growth.powi(2))
+            }
+            Compounding::Continuous => (t, t * t),
+        };
+
+        let present_value = cashflow.amount * discount_factor;
+        sensitivities.value += present_value;
+        sensitivities.time_weighted_value += t * present_value;
+        sensitivities.first_derivative += first * present_value;
+        sensitivities.second_derivative += second * present_value;
+    }
+
+    Ok(sensitivities)
+}
+
+fn per_unit_value(sensitivity: f64, value: f64) -> Result<f64, QuantError> {
+    if value == 0.0 { Err(QuantError::DivisionByZero) } else { Ok(sensitivity / value) }
+}
+
+// Present value weighted average time to the cashflows, in years of the rate's day count
+pub fn macaulay_duration(cashflows: &[CashFlow], valuation_datetime: DateTime<Utc>, interest_rate: &InterestRate) -> Result<f64, QuantError> {
+    let sensitivities = yield_sensitivities(cashflows, valuation_datetime, interest_rate)?;
+    per_unit_value(sensitivities.time_weighted_value, sensitivities.value)
+}
+
+// Relative value change per unit change of the rate, in its own compounding
+pub fn modified_duration(cashflows: &[CashFlow], valuation_datetime: DateTime<Utc>, interest_rate: &InterestRate) -> Result<f64, QuantError> {
+    let sensitivities = yield_sensitivities(cashflows, valuation_datetime, interest_rate)?;
+    per_unit_value(sensitivities.first_derivative, sensitivities.value)
+}
+
+pub fn convexity(cashflows: &[CashFlow], valuation_datetime: DateTime<Utc>, interest_rate: &InterestRate) -> Result<f64, QuantError> {
+    let sensitivities = yield_sensitivities(cashflows, valuation_datetime, interest_rate)?;
+    per_unit_value(sensitivities.second_derivative, sensitivities.value)
+}
+
+// Value lost when the rate rises by one basis point, to first order
+pub fn dv01(cashflows: &[CashFlow], valuation_datetime: DateTime<Utc>, interest_rate: &InterestRate) -> Result<CashFlow, QuantError> {
+    let sensitivities = yield_sensitivities(cashflows, valuation_datetime, interest_rate)?;
+    Ok(CashFlow::new(sensitivities.first_derivative * 1e-4, cashflows[0].currency, valuation_datetime))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::FlatDiscountCurve;
+    use crate::time::date;
+    use super::*;
+
+    // Five year annual 5% bond, per 100 notional
+    fn create_bond() -> Vec<CashFlow> {
+        (1..=5).map(|year| CashFlow::new(if year == 5 { 105.0 } else { 5.0 }, Currency::USD, date(2024 + year, 1, 15))).collect()
+    }
+
+    fn annual_rate(rate: f64) -> InterestRate {
+        InterestRate::new(rate, DayCount::Thirty360Us, Compounding::Periodic(1))
+    }
+
+    #[test]
+    fn test_npv() {
+        let valuation_datetime = date(2024, 1, 15);
+        assert!((npv(&create_bond(), valuation_datetime, &annual_rate(0.05)).unwrap().amount - 100.0).abs() < 1e-10);
+
+        let curve = FlatDiscountCurve::new(valuation_datetime, 0.05, DayCount::Actual365Fixed);
+        let present_value = npv_with_curve(&create_bond(), &curve).unwrap();
+        assert!(present_value.amount < 100.0);
+        assert_eq!(present_value.settlement_datetime, valuation_datetime);
+
+        let mixed = vec![CashFlow::new(1.0, Currency::USD, valuation_datetime), CashFlow::new(1.0, Currency::EUR, valuation_datetime)];
+        assert_eq!(npv(&mixed, valuation_datetime, &annual_rate(0.05)).unwrap_err(), QuantError::CurrencyMismatch { left: Currency::USD, right: Currency::EUR });
+        assert_eq!(npv(&[], valuation_datetime, &annual_rate(0.05)).unwrap_err(), QuantError::EmptyInput { name: "cashflows" });
+    }
+
+    #[test]
+    fn test_irr_and_xirr() {
+        assert!((irr(&[-100.0, 39.0, 59.0, 55.0, 20.0]).unwrap() - 0.2809484211599611).abs() < 1e-10);
+        assert!(irr(&[-100.0, 130.0]).is_ok_and(|rate| (rate - 0.3).abs() < 1e-12));
+        assert_eq!(irr(&[100.0, 50.0]), Err(QuantError::RootNotFound { name: "internal rate of return" }));
+        assert!(irr(&[-1.0, 0.0, 9000.0]).is_ok_and(|rate| (rate - (9000f64.sqrt() - 1.0)).abs() < 1e-10));
+
+        let cashflows = vec![
+            CashFlow::new(-10000.0, Currency::USD, date(2008, 1, 1)),
+            CashFlow::new(2750.0, Currency::USD, date(2008, 3, 1)),
+            CashFlow::new(4250.0, Currency::USD, date(2008, 10, 30)),
+            CashFlow::new(3250.0, Currency::USD, date(2009, 2, 15)),
+            CashFlow::new(2750.0, Currency::USD, date(2009, 4, 1)),
+        ];
+        assert!((xirr(&cashflows).unwrap() - 0.373362535).abs() < 1e-8);
+
+        // A bond bought at par yields its coupon
+        let mut bond = create_bond();
+        bond.push(CashFlow::new(-100.0, Currency::USD, date(2024, 1, 15)));
+        assert!((xirr(&bond).unwrap() - 0.05).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_duration_and_convexity() {
+        let (valuation_datetime, rate) = (date(2024, 1, 15), annual_rate(0.05));
+        let bond = create_bond();
+
+        assert!((macaulay_duration(&bond, valuation_datetime, &rate).unwrap() - 4.545950504).abs() < 1e-8);
+        assert!((modified_duration(&bond, valuation_datetime, &rate).unwrap() - 4.545950504 / 1.05).abs() < 1e-8);
+
+        for compounding in [Compounding::Simple, Compounding::Periodic(2), Compounding::Continuous] {
+            let h = 1e-4;
+            let value = |rate: f64| npv(&bond, valuation_datetime, &InterestRate::new(rate, DayCount::Thirty360Us, compounding)).unwrap().amount;
+            let rate = InterestRate::new(0.05, DayCount::Thirty360Us, compounding);
+
+            let bumped_dv01 = (value(0.05 - h) - value(0.05 + h)) / 2.0;
+            let bumped_convexity = (value(0.05 + h) - 2.0 * value(0.05) + value(0.05 - h)) / (h * h * value(0.05));
+            assert!((dv01(&bond, valuation_datetime, &rate).unwrap().amount - bumped_dv01).abs() < 1e-8, "{:?}", compounding);
+            assert!((convexity(&bond, valuation_datetime, &rate).unwrap() - bumped_convexity).abs() < 1e-4, "{:?}", compounding);
+        }
+
+        assert_eq!(modified_duration(&[CashFlow::new(0.0, Currency::USD, valuation_datetime)], valuation_datetime, &rate), Err(QuantError::DivisionByZero));
+    }
+}
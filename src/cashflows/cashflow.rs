@@ -5,9 +5,13 @@ use std::iter::Sum;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use chrono::{DateTime, Utc};
-use crate::cashflows::Currency;
+use crate::cashflows::{Currency, FxRates};
+use crate::curves::{Compounding, DiscountCurve, InterestRate};
+use crate::error::QuantError;
+use crate::time::{BusinessDayConvention, Calendar, DayCount};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CashFlow {
     pub amount: f64,
     pub currency: Currency,
@@ -23,24 +27,63 @@ impl CashFlow {
         CashFlow::new(self.amount * conversion_rate, other_currency, self.settlement_datetime)
     }
 
+    // Converts using the quote for this pair held by `fx_rates`, whatever its quoting direction
+    pub fn convert_with_rates(&self, other_currency: Currency, fx_rates: &FxRates) -> Result<CashFlow, QuantError> {
+        fx_rates.convert(self, other_currency)
+    }
+
     pub fn value_at_date(&self, valuation_datetime: DateTime<Utc>, annual_discount_rate: f64) -> CashFlow {
-        let duration = self.settlement_datetime - valuation_datetime;
-        let years_to_settlement = duration.num_seconds() as f64 / (365.25 * 24.0 * 3600.0);
+        self.value_at_date_with_day_count(valuation_datetime, annual_discount_rate, DayCount::Actual365Fixed)
+    }
+
+    pub fn value_at_date_with_day_count(&self, valuation_datetime: DateTime<Utc>, annual_discount_rate: f64, day_count: DayCount) -> CashFlow {
+        self.value_at_date_with_rate(valuation_datetime, &InterestRate::new(annual_discount_rate, day_count, Compounding::Periodic(1)))
+    }
+
+    pub fn value_at_date_with_rate(&self, valuation_datetime: DateTime<Utc>, interest_rate: &InterestRate) -> CashFlow {
+        CashFlow::new(self.amount / interest_rate.compound_factor(valuation_datetime, self.settlement_datetime), self.currency, valuation_datetime)
+    }
+
+    pub fn value_with_curve(&self, discount_curve: &dyn DiscountCurve) -> CashFlow {
+        discount_curve.present_value(self)
+    }
+
+    pub fn adjusted(&self, calendar: &Calendar, convention: BusinessDayConvention) -> CashFlow {
+        CashFlow::new(self.amount, self.currency, calendar.adjust(self.settlement_datetime, convention))
+    }
+
+    pub fn checked_add(&self, other: &CashFlow) -> Result<CashFlow, QuantError> {
+        self.check_compatible_with(other)?;
+        Ok(CashFlow::new(self.amount + other.amount, self.currency, self.settlement_datetime))
+    }
+
+    pub fn checked_sub(&self, other: &CashFlow) -> Result<CashFlow, QuantError> {
+        self.check_compatible_with(other)?;
+        Ok(CashFlow::new(self.amount - other.amount, self.currency, self.settlement_datetime))
+    }
 
-        CashFlow::new(self.amount / (1.0 + annual_discount_rate).powf(years_to_settlement), self.currency, valuation_datetime)
+    pub fn checked_div(&self, divisor: f64) -> Result<CashFlow, QuantError> {
+        if divisor == 0.0 {
+            return Err(QuantError::DivisionByZero);
+        }
+        Ok(CashFlow::new(self.amount / divisor, self.currency, self.settlement_datetime))
     }
 
-    fn validate_operation_with(&self, other: &CashFlow) -> bool {
+    fn check_compatible_with(&self, other: &CashFlow) -> Result<(), QuantError> {
         if self.settlement_datetime != other.settlement_datetime {
-            panic!("Cannot operate on cashflows with different settlement dates.");
+            Err(QuantError::SettlementMismatch { left: self.settlement_datetime, right: other.settlement_datetime })
         }
         else if self.currency != other.currency {
-            panic!("Cannot operate on cashflows with different currencies.");
+            Err(QuantError::CurrencyMismatch { left: self.currency, right: other.currency })
         }
-        else{
-            true
+        else {
+            Ok(())
         }
     }
+
+    fn validate_operation_with(&self, other: &CashFlow) {
+        self.check_compatible_with(other).unwrap_or_else(|error| panic!("{}", error));
+    }
 }
 
 impl fmt::Display for CashFlow {
@@ -117,28 +160,19 @@ impl Div<f64> for CashFlow {
     type Output = Self;
 
     fn div(self, rhs: f64) -> Self::Output {
-        if rhs == 0.0 {
-            panic!("Attempt to divide by zero.");
-        }
-        CashFlow {
-            amount: self.amount / rhs,
-            currency: self.currency,
-            settlement_datetime: self.settlement_datetime,
-        }
+        self.checked_div(rhs).unwrap_or_else(|error| panic!("{}", error))
     }
 }
 
 
 impl DivAssign<f64> for CashFlow {
     fn div_assign(&mut self, divisor: f64) {
-        if divisor == 0.0 {
-            panic!("Attempt to divide by zero.");
-        } else {
-            self.amount /= divisor;
-        }
+        *self = self.checked_div(divisor).unwrap_or_else(|error| panic!("{}", error));
     }
 }
 
+// An empty iterator sums to a zero USD cashflow settling now, whatever the currency of the cashflows it would have held.
+// Where that matters, sum into a `Result` or fold from a zero cashflow with the expected currency and date instead.
 impl Sum for CashFlow {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self
     {
@@ -147,6 +181,14 @@ impl Sum for CashFlow {
     }
 }
 
+// Fallible sum, failing on incompatible cashflows and on an empty iterator rather than inventing a cashflow
+impl Sum<CashFlow> for Result<CashFlow, QuantError> {
+    fn sum<I: Iterator<Item = CashFlow>>(mut iter: I) -> Self {
+        let first = iter.next().ok_or(QuantError::EmptyInput { name: "cashflows" })?;
+        iter.try_fold(first, |acc, x| acc.checked_add(&x))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::TimeZone;
@@ -371,6 +413,48 @@ mod tests {
         assert_eq!(converted_cash_flow.settlement_datetime, initial_cash_flow.settlement_datetime);
     }
 
+    #[test]
+    fn test_checked_arithmetic() {
+        let settlement_datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let cf1 = CashFlow::new(100.0, Currency::USD, settlement_datetime);
+        let cf2 = CashFlow::new(40.0, Currency::USD, settlement_datetime);
+        let cf3 = CashFlow::new(40.0, Currency::EUR, settlement_datetime);
+
+        assert_eq!(cf1.checked_add(&cf2).unwrap().amount, 140.0);
+        assert_eq!(cf1.checked_sub(&cf2).unwrap().amount, 60.0);
+        assert_eq!(cf1.checked_div(4.0).unwrap().amount, 25.0);
+        assert_eq!(cf1.checked_add(&cf3), Err(QuantError::CurrencyMismatch { left: Currency::USD, right: Currency::EUR }));
+        assert_eq!(cf1.checked_div(0.0), Err(QuantError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_fallible_sum() {
+        let settlement_datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let cashflows = vec![CashFlow::new(100.0, Currency::USD, settlement_datetime), CashFlow::new(50.0, Currency::USD, settlement_datetime)];
+
+        let total: Result<CashFlow, QuantError> = cashflows.into_iter().sum();
+        assert_eq!(total.unwrap().amount, 150.0);
+
+        let empty: Result<CashFlow, QuantError> = Vec::<CashFlow>::new().into_iter().sum();
+        assert_eq!(empty, Err(QuantError::EmptyInput { name: "cashflows" }));
+
+        let mixed: Result<CashFlow, QuantError> = vec![
+            CashFlow::new(100.0, Currency::USD, settlement_datetime),
+            CashFlow::new(100.0, Currency::USD, Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()),
+        ].into_iter().sum();
+        assert!(matches!(mixed, Err(QuantError::SettlementMismatch { .. })));
+    }
+
+    #[test]
+    fn test_settlement_rolls_off_weekend() {
+        let saturday = Utc.with_ymd_and_hms(2024, 6, 29, 0, 0, 0).unwrap();
+        let cashflow = CashFlow::new(100.0, Currency::USD, saturday);
+
+        let adjusted = cashflow.adjusted(&Calendar::Nyse, BusinessDayConvention::ModifiedFollowing);
+        assert_eq!(adjusted.settlement_datetime, Utc.with_ymd_and_hms(2024, 6, 28, 0, 0, 0).unwrap());
+        assert_eq!(adjusted.amount, 100.0);
+    }
+
     #[test]
     fn test_present_value_one_year() {
         let amount = 100.0;
@@ -381,7 +465,7 @@ mod tests {
         let cashflow = CashFlow::new(amount, Currency::USD, settlement_datetime);
         let present_value = cashflow.value_at_date(valuation_datetime, discount_rate);
 
-        let expected_present_value = CashFlow::new(95.2412757719014, Currency::USD, valuation_datetime);
+        let expected_present_value = CashFlow::new(95.23809523809524, Currency::USD, valuation_datetime);
         assert_eq!(expected_present_value, present_value);
     }
 
@@ -395,7 +479,7 @@ mod tests {
         let cashflow = CashFlow::new(amount, Currency::USD, settlement_datetime);
         let present_value = cashflow.value_at_date(valuation_datetime, discount_rate);
 
-        let expected_present_value = CashFlow::new(97.61119324310415, Currency::USD, valuation_datetime);
+        let expected_present_value = CashFlow::new(97.60957678743128, Currency::USD, valuation_datetime);
         assert_eq!(expected_present_value, present_value);
     }
 
@@ -409,10 +493,20 @@ mod tests {
         let cashflow = CashFlow::new(amount, Currency::USD, settlement_datetime);
         let present_value = cashflow.value_at_date(valuation_datetime, discount_rate);
 
-        let expected_present_value = CashFlow::new(101.00940615592717, Currency::USD, valuation_datetime);
+        let expected_present_value = CashFlow::new(101.01010101010101, Currency::USD, valuation_datetime);
         assert_eq!(expected_present_value, present_value);
     }
 
+    #[test]
+    fn test_present_value_with_continuous_rate() {
+        let settlement_datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let valuation_datetime = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let cashflow = CashFlow::new(100.0, Currency::USD, settlement_datetime);
+
+        let present_value = cashflow.value_at_date_with_rate(valuation_datetime, &InterestRate::continuous(0.05));
+        assert!((present_value.amount - 100.0 * (-0.05f64).exp()).abs() < 1e-12);
+    }
+
     #[test]
     fn test_future_value_one_year() {
         let amount = 100.0;
@@ -423,7 +517,18 @@ mod tests {
         let cashflow = CashFlow::new(amount, Currency::USD, settlement_datetime);
         let present_value = cashflow.value_at_date(valuation_datetime, discount_rate);
 
-        let expected_present_value = CashFlow::new(104.99649357857778, Currency::USD, valuation_datetime);
+        let expected_present_value = CashFlow::new(105.0, Currency::USD, valuation_datetime);
         assert_eq!(expected_present_value, present_value);
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let cashflow = CashFlow::new(1250.5, Currency::EUR, Utc.with_ymd_and_hms(2024, 3, 15, 12, 30, 0).unwrap());
+        let json = serde_json::to_string(&cashflow).unwrap();
+
+        assert_eq!(json, r#"{"amount":1250.5,"currency":"EUR","settlement_datetime":"2024-03-15T12:30:00Z"}"#);
+        assert_eq!(serde_json::from_str::<CashFlow>(&json).unwrap(), cashflow);
+        assert_eq!(serde_json::from_str::<CashFlow>(r#"{"amount":1.0,"currency":"EUR","settlement_datetime":"2024-03-15T14:30:00+02:00"}"#).unwrap().settlement_datetime, cashflow.settlement_datetime);
+    }
+}
@@ -0,0 +1,204 @@
+use chrono::{DateTime, Utc};
+
+use crate::cashflows::{CashFlow, Currency, RoundingMode, SettlementCashFlow};
+use crate::error::QuantError;
+
+// Collection of cashflows in any currencies and on any dates, combined by netting rather than by `CashFlow` arithmetic
+#[derive(Clone, Debug, Default)]
+pub struct CashFlowSet {
+    cashflows: Vec<CashFlow>,
+}
+
+impl CashFlowSet {
+    pub fn new(cashflows: Vec<CashFlow>) -> Self {
+        CashFlowSet { cashflows }
+    }
+
+    pub fn push(&mut self, cashflow: CashFlow) {
+        self.cashflows.push(cashflow);
+    }
+
+    pub fn cashflows(&self) -> &[CashFlow] {
+        &self.cashflows
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, CashFlow> {
+        self.cashflows.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cashflows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cashflows.is_empty()
+    }
+
+    pub fn currencies(&self) -> Vec<Currency> {
+        let mut currencies: Vec<Currency> = Vec::new();
+        for cashflow in self.cashflows.iter() {
+            if !currencies.contains(&cashflow.currency) {
+                currencies.push(cashflow.currency);
+            }
+        }
+        currencies
+    }
+
+    // One cashflow per (currency, settlement date), ordered by settlement date
+    pub fn netted(&self) -> CashFlowSet {
+        let mut netted: Vec<CashFlow> = Vec::new();
+        for cashflow in self.cashflows.iter() {
+            match netted.iter_mut().find(|net| net.currency == cashflow.currency && net.settlement_datetime == cashflow.settlement_datetime) {
+                Some(net) => net.amount += cashflow.amount,
+                None => netted.push(cashflow.clone()),
+            }
+        }
+        netted.sort_by_key(|cashflow| cashflow.settlement_datetime);
+
+        CashFlowSet::new(netted)
+    }
+
+    // Netted amounts rounded to each currency's minor units, ready for settlement or reporting
+    pub fn settlement_cashflows(&self, rounding_mode: RoundingMode) -> Result<Vec<SettlementCashFlow>, QuantError> {
+        self.netted().iter().map(|cashflow| SettlementCashFlow::from_cashflow(cashflow, rounding_mode)).collect()
+    }
+
+    pub fn filter<P: Fn(&CashFlow) -> bool>(&self, predicate: P) -> CashFlowSet {
+        CashFlowSet::new(self.cashflows.iter().filter(|cashflow| predicate(cashflow)).cloned().collect())
+    }
+
+    pub fn in_currency(&self, currency: Currency) -> CashFlowSet {
+        self.filter(|cashflow| cashflow.currency == currency)
+    }
+
+    // Cashflows settling in [start, end)
+    pub fn between(&self, start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>) -> CashFlowSet {
+        self.filter(|cashflow| cashflow.settlement_datetime >= start_datetime && cashflow.settlement_datetime < end_datetime)
+    }
+
+    // Splits the cashflows into the periods between consecutive `boundaries`, dropping those outside the first and last
+    pub fn bucketed(&self, boundaries: &[DateTime<Utc>]) -> Vec<(DateTime<Utc>, DateTime<Utc>, CashFlowSet)> {
+        boundaries.windows(2)
+            .map(|window| (window[0], window[1], self.between(window[0], window[1])))
+            .collect()
+    }
+
+    pub fn total(&self, currency: Currency) -> f64 {
+        self.in_currency(currency).iter().map(|cashflow| cashflow.amount).sum()
+    }
+
+    // Present value in `reporting_currency`, discounting each cashflow at its own currency's annual rate before converting
+    // with `conversion_rate`, the price of one unit of a currency in the reporting currency. Returns None when a
+    // conversion rate is missing.
+    pub fn present_value<D, X>(&self, valuation_datetime: DateTime<Utc>, reporting_currency: Currency, discount_rate: D, conversion_rate: X) -> Option<CashFlow>
+    where
+        D: Fn(Currency) -> f64,
+        X: Fn(Currency) -> Option<f64>,
+    {
+        self.cashflows.iter().try_fold(CashFlow::new(0.0, reporting_currency, valuation_datetime), |mut present_value, cashflow| {
+            let rate = if cashflow.currency == reporting_currency { 1.0 } else { conversion_rate(cashflow.currency)? };
+            present_value.amount += cashflow.value_at_date(valuation_datetime, discount_rate(cashflow.currency)).amount * rate;
+            Some(present_value)
+        })
+    }
+}
+
+impl From<Vec<CashFlow>> for CashFlowSet {
+    fn from(cashflows: Vec<CashFlow>) -> Self {
+        CashFlowSet::new(cashflows)
+    }
+}
+
+impl FromIterator<CashFlow> for CashFlowSet {
+    fn from_iter<I: IntoIterator<Item = CashFlow>>(iter: I) -> Self {
+        CashFlowSet::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<CashFlow> for CashFlowSet {
+    fn extend<I: IntoIterator<Item = CashFlow>>(&mut self, iter: I) {
+        self.cashflows.extend(iter);
+    }
+}
+
+impl IntoIterator for CashFlowSet {
+    type Item = CashFlow;
+    type IntoIter = std::vec::IntoIter<CashFlow>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cashflows.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::date;
+    use super::*;
+
+    fn create_set() -> CashFlowSet {
+        CashFlowSet::new(vec![
+            CashFlow::new(100.0, Currency::USD, date(2025, 1, 1)),
+            CashFlow::new(-40.0, Currency::USD, date(2025, 1, 1)),
+            CashFlow::new(50.0, Currency::EUR, date(2025, 1, 1)),
+            CashFlow::new(25.0, Currency::USD, date(2024, 7, 1)),
+        ])
+    }
+
+    #[test]
+    fn test_netting_by_currency_and_date() {
+        let netted = create_set().netted();
+
+        assert_eq!(netted.len(), 3);
+        assert_eq!(netted.cashflows()[0], CashFlow::new(25.0, Currency::USD, date(2024, 7, 1)));
+        assert_eq!(netted.in_currency(Currency::USD).total(Currency::USD), 85.0);
+        assert_eq!(netted.total(Currency::EUR), 50.0);
+    }
+
+    #[test]
+    fn test_settlement_cashflows_are_netted_and_rounded() {
+        let mut set = create_set();
+        set.push(CashFlow::new(1000.505, Currency::JPY, date(2025, 1, 1)));
+        let settlement = set.settlement_cashflows(RoundingMode::HalfUp).unwrap();
+
+        assert_eq!(settlement.len(), 4);
+        assert_eq!(settlement[1].to_string(), "USD 60.00");
+        assert_eq!(settlement[3].to_string(), "JPY 1001");
+    }
+
+    #[test]
+    fn test_bucketing() {
+        let buckets = create_set().bucketed(&[date(2024, 1, 1), date(2025, 1, 1), date(2026, 1, 1)]);
+
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].2.len(), 1);
+        assert_eq!(buckets[1].2.len(), 3);
+        assert_eq!(buckets[1].2.currencies(), vec![Currency::USD, Currency::EUR]);
+    }
+
+    #[test]
+    fn test_present_value_in_reporting_currency() {
+        let valuation_datetime = date(2024, 1, 1);
+        let set = CashFlowSet::new(vec![
+            CashFlow::new(105.0, Currency::USD, date(2024, 12, 31)),
+            CashFlow::new(100.0, Currency::EUR, valuation_datetime),
+        ]);
+
+        let eur_usd = |currency| match currency {
+            Currency::EUR => Some(1.1),
+            _ => None,
+        };
+        let present_value = set.present_value(valuation_datetime, Currency::USD, |_| 0.05, eur_usd).unwrap();
+
+        assert!((present_value.amount - 210.0).abs() < 1e-10);
+        assert_eq!(present_value.currency, Currency::USD);
+        assert!(set.present_value(valuation_datetime, Currency::JPY, |_| 0.05, eur_usd).is_none());
+    }
+
+    #[test]
+    fn test_empty_set_has_zero_present_value() {
+        let present_value = CashFlowSet::default().present_value(date(2024, 1, 1), Currency::EUR, |_| 0.05, |_| None).unwrap();
+
+        assert_eq!(present_value.amount, 0.0);
+        assert_eq!(present_value.currency, Currency::EUR);
+    }
+}
@@ -1,29 +1,280 @@
 use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+use crate::error::QuantError;
+use crate::time::DayCount;
+
+// Major currencies with their ISO 4217 metadata built in. This is not the full ISO 4217 list: other ISO currencies,
+// such as PEN or VND, are added like crypto assets with `Currency::register` before they can be parsed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Currency {
+    AED,
     AUD,
+    BHD,
+    BRL,
     CAD,
     CHF,
+    CLP,
     CNH,
+    CNY,
+    COP,
     CZK,
     DKK,
     EUR,
+    GBP,
     HKD,
     HUF,
+    IDR,
+    ILS,
+    INR,
+    ISK,
     JPY,
+    KRW,
+    KWD,
     MXN,
     NOK,
     NZD,
     PLN,
+    SAR,
     SEK,
     SGD,
+    THB,
+    TRY,
+    TWD,
     USD,
     ZAR,
+    Custom(CustomCurrency), // Registered at runtime with `Currency::register`
+}
+
+// Handle to an entry of the custom currency registry, only obtainable by registering a currency
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CustomCurrency(usize);
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CurrencyInfo {
+    pub code: &'static str,
+    pub numeric_code: Option<u16>, // None for currencies without an ISO 4217 number, such as CNH or crypto assets
+    pub minor_units: u8,
+    pub name: &'static str,
+    pub day_count: DayCount, // Money market day count convention
+    pub spot_lag: u32, // Business days from trade to spot settlement
+}
+
+static CUSTOM_CURRENCIES: RwLock<Vec<CurrencyInfo>> = RwLock::new(Vec::new());
+
+const fn iso(code: &'static str, numeric_code: u16, minor_units: u8, name: &'static str, day_count: DayCount, spot_lag: u32) -> CurrencyInfo {
+    CurrencyInfo { code, numeric_code: Some(numeric_code), minor_units, name, day_count, spot_lag }
+}
+
+impl Currency {
+    pub const ISO_CURRENCIES: [Currency; 35] = [
+        Currency::AED, Currency::AUD, Currency::BHD, Currency::BRL, Currency::CAD, Currency::CHF, Currency::CLP,
+        Currency::CNH, Currency::CNY, Currency::COP, Currency::CZK, Currency::DKK, Currency::EUR, Currency::GBP,
+        Currency::HKD, Currency::HUF, Currency::IDR, Currency::ILS, Currency::INR, Currency::ISK, Currency::JPY,
+        Currency::KRW, Currency::KWD, Currency::MXN, Currency::NOK, Currency::NZD, Currency::PLN, Currency::SAR,
+        Currency::SEK, Currency::SGD, Currency::THB, Currency::TRY, Currency::TWD, Currency::USD, Currency::ZAR,
+    ];
+
+    pub fn info(&self) -> CurrencyInfo {
+        use DayCount::{Actual360, Actual365Fixed, Business252};
+
+        match self {
+            Currency::AED => iso("AED", 784, 2, "UAE Dirham", Actual360, 2),
+            Currency::AUD => iso("AUD", 36, 2, "Australian Dollar", Actual365Fixed, 2),
+            Currency::BHD => iso("BHD", 48, 3, "Bahraini Dinar", Actual360, 2),
+            Currency::BRL => iso("BRL", 986, 2, "Brazilian Real", Business252, 2),
+            Currency::CAD => iso("CAD", 124, 2, "Canadian Dollar", Actual365Fixed, 1),
+            Currency::CHF => iso("CHF", 756, 2, "Swiss Franc", Actual360, 2),
+            Currency::CLP => iso("CLP", 152, 0, "Chilean Peso", Actual360, 2),
+            Currency::CNH => CurrencyInfo { code: "CNH", numeric_code: None, minor_units: 2, name: "Offshore Chinese Yuan", day_count: Actual365Fixed, spot_lag: 2 },
+            Currency::CNY => iso("CNY", 156, 2, "Chinese Yuan", Actual365Fixed, 2),
+            Currency::COP => iso("COP", 170, 2, "Colombian Peso", Actual360, 2),
+            Currency::CZK => iso("CZK", 203, 2, "Czech Koruna", Actual360, 2),
+            Currency::DKK => iso("DKK", 208, 2, "Danish Krone", Actual360, 2),
+            Currency::EUR => iso("EUR", 978, 2, "Euro", Actual360, 2),
+            Currency::GBP => iso("GBP", 826, 2, "Pound Sterling", Actual365Fixed, 2),
+            Currency::HKD => iso("HKD", 344, 2, "Hong Kong Dollar", Actual365Fixed, 2),
+            Currency::HUF => iso("HUF", 348, 2, "Hungarian Forint", Actual360, 2),
+            Currency::IDR => iso("IDR", 360, 2, "Indonesian Rupiah", Actual360, 2),
+            Currency::ILS => iso("ILS", 376, 2, "Israeli New Shekel", Actual365Fixed, 2),
+            Currency::INR => iso("INR", 356, 2, "Indian Rupee", Actual365Fixed, 2),
+            Currency::ISK => iso("ISK", 352, 0, "Icelandic Krona", Actual360, 2),
+            Currency::JPY => iso("JPY", 392, 0, "Japanese Yen", Actual365Fixed, 2),
+            Currency::KRW => iso("KRW", 410, 0, "South Korean Won", Actual365Fixed, 2),
+            Currency::KWD => iso("KWD", 414, 3, "Kuwaiti Dinar", Actual360, 2),
+            Currency::MXN => iso("MXN", 484, 2, "Mexican Peso", Actual360, 2),
+            Currency::NOK => iso("NOK", 578, 2, "Norwegian Krone", Actual360, 2),
+            Currency::NZD => iso("NZD", 554, 2, "New Zealand Dollar", Actual365Fixed, 2),
+            Currency::PLN => iso("PLN", 985, 2, "Polish Zloty", Actual365Fixed, 2),
+            Currency::SAR => iso("SAR", 682, 2, "Saudi Riyal", Actual360, 2),
+            Currency::SEK => iso("SEK", 752, 2, "Swedish Krona", Actual360, 2),
+            Currency::SGD => iso("SGD", 702, 2, "Singapore Dollar", Actual365Fixed, 2),
+            Currency::THB => iso("THB", 764, 2, "Thai Baht", Actual365Fixed, 2),
+            Currency::TRY => iso("TRY", 949, 2, "Turkish Lira", Actual360, 1),
+            Currency::TWD => iso("TWD", 901, 2, "New Taiwan Dollar", Actual365Fixed, 2),
+            Currency::USD => iso("USD", 840, 2, "US Dollar", Actual360, 2),
+            Currency::ZAR => iso("ZAR", 710, 2, "South African Rand", Actual365Fixed, 2),
+            Currency::Custom(CustomCurrency(index)) => CUSTOM_CURRENCIES.read().unwrap()[*index],
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.info().code
+    }
+
+    pub fn numeric_code(&self) -> Option<u16> {
+        self.info().numeric_code
+    }
+
+    pub fn minor_units(&self) -> u8 {
+        self.info().minor_units
+    }
+
+    pub fn day_count(&self) -> DayCount {
+        self.info().day_count
+    }
+
+    pub fn spot_lag(&self) -> u32 {
+        self.info().spot_lag
+    }
+
+    fn custom_currencies() -> Vec<Currency> {
+        (0..CUSTOM_CURRENCIES.read().unwrap().len()).map(|index| Currency::Custom(CustomCurrency(index))).collect()
+    }
+
+    pub fn from_numeric_code(numeric_code: u16) -> Option<Currency> {
+        Currency::ISO_CURRENCIES.into_iter()
+            .chain(Currency::custom_currencies())
+            .find(|currency| currency.numeric_code() == Some(numeric_code))
+    }
+
+    // Adds a currency that is not built in, e.g. an ISO currency outside the major ones, a crypto asset or an internal
+    // test currency. Codes are upper-cased and must not clash with an existing currency.
+    pub fn register(code: &str, numeric_code: Option<u16>, minor_units: u8, name: &str, day_count: DayCount, spot_lag: u32) -> Result<Currency, QuantError> {
+        let code = code.trim().to_ascii_uppercase();
+        if code.is_empty() || !code.chars().all(|character| character.is_ascii_alphanumeric()) {
+            return Err(QuantError::InvalidCurrencyCode { code });
+        }
+
+        let mut registry = CUSTOM_CURRENCIES.write().unwrap();
+        let clashes = |info: CurrencyInfo| info.code == code || (numeric_code.is_some() && info.numeric_code == numeric_code);
+        if Currency::ISO_CURRENCIES.iter().any(|currency| clashes(currency.info())) || registry.iter().any(|info| clashes(*info)) {
+            return Err(QuantError::DuplicateCurrency { code });
+        }
+
+        // Registered currencies live for the rest of the program, so their strings are leaked to hand out &'static str
+        registry.push(CurrencyInfo {
+            code: Box::leak(code.into_boxed_str()),
+            numeric_code,
+            minor_units,
+            name: Box::leak(name.to_string().into_boxed_str()),
+            day_count,
+            spot_lag,
+        });
+
+        Ok(Currency::Custom(CustomCurrency(registry.len() - 1)))
+    }
+}
+
+impl FromStr for Currency {
+    type Err = QuantError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        let code = code.trim().to_ascii_uppercase();
+        Currency::ISO_CURRENCIES.into_iter()
+            .chain(Currency::custom_currencies())
+            .find(|currency| currency.code() == code)
+            .ok_or(QuantError::UnknownCurrency { code })
+    }
 }
 
 impl fmt::Display for Currency {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.code())
+    }
+}
+
+// Serialized as the currency code, so custom currencies must be registered before they can be deserialized
+#[cfg(feature = "serde")]
+impl serde::Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Currency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_iso_metadata() {
+        assert_eq!(Currency::USD.numeric_code(), Some(840));
+        assert_eq!(Currency::JPY.minor_units(), 0);
+        assert_eq!(Currency::KWD.minor_units(), 3);
+        assert_eq!(Currency::GBP.day_count(), DayCount::Actual365Fixed);
+        assert_eq!(Currency::CAD.spot_lag(), 1);
+        assert_eq!(Currency::from_numeric_code(978), Some(Currency::EUR));
+        assert_eq!(Currency::CNH.numeric_code(), None);
+    }
+
+    #[test]
+    fn test_parsing_and_display() {
+        assert_eq!("usd".parse::<Currency>().unwrap(), Currency::USD);
+        assert_eq!(" BRL ".parse::<Currency>().unwrap(), Currency::BRL);
+        assert_eq!(Currency::KRW.to_string(), "KRW");
+        assert_eq!("XYZ".parse::<Currency>(), Err(QuantError::UnknownCurrency { code: "XYZ".to_string() }));
+
+        for currency in Currency::ISO_CURRENCIES {
+            assert_eq!(currency.code().parse::<Currency>().unwrap(), currency);
+        }
+    }
+
+    #[test]
+    fn test_hash_and_eq() {
+        let currencies: HashSet<Currency> = [Currency::USD, Currency::EUR, Currency::USD].into_iter().collect();
+        assert_eq!(currencies.len(), 2);
+    }
+
+    #[test]
+    fn test_register_custom_currency() {
+        let usdt = Currency::register("usdt", None, 6, "Tether", DayCount::Actual365Fixed, 0).unwrap();
+
+        assert_eq!(usdt.code(), "USDT");
+        assert_eq!(usdt.minor_units(), 6);
+        assert_eq!("USDT".parse::<Currency>().unwrap(), usdt);
+        assert_eq!(usdt.to_string(), "USDT");
+        assert_ne!(usdt, Currency::USD);
+
+        assert_eq!(Currency::register("USDT", None, 6, "Tether", DayCount::Actual365Fixed, 0), Err(QuantError::DuplicateCurrency { code: "USDT".to_string() }));
+        assert_eq!(Currency::register("EUR", None, 2, "Euro", DayCount::Actual360, 2), Err(QuantError::DuplicateCurrency { code: "EUR".to_string() }));
+        assert!(Currency::register("BAD CODE", None, 2, "Invalid", DayCount::Actual360, 2).is_err());
+
+        // ISO currencies outside the built-in major ones parse once registered with their ISO metadata
+        assert!("PEN".parse::<Currency>().is_err());
+        let pen = Currency::register("PEN", Some(604), 2, "Peruvian Sol", DayCount::Actual360, 2).unwrap();
+        assert_eq!("pen".parse::<Currency>().unwrap(), pen);
+        assert_eq!(Currency::from_numeric_code(604), Some(pen));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        assert_eq!(serde_json::to_string(&Currency::JPY).unwrap(), r#""JPY""#);
+        assert_eq!(serde_json::from_str::<Currency>(r#""gbp""#).unwrap(), Currency::GBP);
+        assert!(serde_json::from_str::<Currency>(r#""XYZ""#).unwrap_err().to_string().contains("Unknown currency code XYZ"));
+
+        let wbtc = Currency::register("WBTC", None, 8, "Wrapped Bitcoin", DayCount::Actual365Fixed, 0).unwrap();
+        assert_eq!(serde_json::from_str::<Currency>(&serde_json::to_string(&wbtc).unwrap()).unwrap(), wbtc);
     }
 }
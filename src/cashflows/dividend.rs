@@ -0,0 +1,180 @@
+use chrono::{DateTime, Utc};
+
+use crate::processes::process_error::{check_non_negative, ProcessError};
+use crate::time::DayCount;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DividendType {
+    Cash(f64),
+    Proportional(f64),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dividend {
+    pub ex_datetime: DateTime<Utc>,
+    pub dividend_type: DividendType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DividendSchedule {
+    pub dividends: Vec<Dividend>,
+}
+
+impl Dividend {
+    pub fn cash(amount: f64, ex_datetime: DateTime<Utc>) -> Self {
+        Dividend { ex_datetime, dividend_type: DividendType::Cash(amount) }
+    }
+
+    pub fn proportional(ratio: f64, ex_datetime: DateTime<Utc>) -> Self {
+        Dividend { ex_datetime, dividend_type: DividendType::Proportional(ratio) }
+    }
+}
+
+impl DividendType {
+    // Price of the underlying just after going ex-dividend
+    pub fn apply(&self, price: f64) -> f64 {
+        match self {
+            DividendType::Cash(amount) => (price - amount).max(0.0),
+            DividendType::Proportional(ratio) => price * (1.0 - ratio),
+        }
+    }
+}
+
+impl DividendSchedule {
+    pub fn new(dividends: Vec<Dividend>) -> Self {
+        let mut dividends = dividends;
+        dividends.sort_by_key(|dividend| dividend.ex_datetime);
+
+        DividendSchedule { dividends }
+    }
+
+    pub fn try_new(dividends: Vec<Dividend>) -> Result<Self, ProcessError> {
+        let schedule = DividendSchedule::new(dividends);
+        schedule.validate()?;
+        Ok(schedule)
+    }
+
+    // Cash amounts must not be negative and ratios must lie in [0, 1), so that the spot stays positive and the
+    // dividends can be added back onto an escrowed price
+    pub fn validate(&self) -> Result<(), ProcessError> {
+        for dividend in &self.dividends {
+            match dividend.dividend_type {
+                DividendType::Cash(amount) => check_non_negative("dividend amount", amount)?,
+                DividendType::Proportional(ratio) => {
+                    check_non_negative("dividend ratio", ratio)?;
+                    if ratio >= 1.0 {
+                        return Err(ProcessError::InvalidParameter { name: "dividend ratio", value: ratio, reason: "must be below 1" });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Dividends going ex after `valuation_datetime`, as (years to ex-date, dividend) pairs
+    pub fn upcoming(&self, valuation_datetime: DateTime<Utc>) -> Vec<(f64, DividendType)> {
+        self.dividends.iter()
+            .filter(|dividend| dividend.ex_datetime > valuation_datetime)
+            .map(|dividend| {
+                let years_to_ex_date = DayCount::Actual365Fixed.year_fraction(valuation_datetime, dividend.ex_datetime);
+                (years_to_ex_date, dividend.dividend_type)
+            })
+            .collect()
+    }
+
+    // Spot price net of the dividends paid before `expiry`, with cash dividends escrowed at the continuous rate `r`
+    pub fn escrowed_spot(&self, s0: f64, r: f64, valuation_datetime: DateTime<Utc>, expiry_datetime: DateTime<Utc>) -> f64 {
+        let expiry = DayCount::Actual365Fixed.year_fraction(valuation_datetime, expiry_datetime);
+        self.escrowed_spot_between(s0, r, 0.0, expiry, &self.upcoming(valuation_datetime))
+    }
+
+    pub(crate) fn escrowed_spot_between(&self, spot: f64, r: f64, time: f64, expiry: f64, upcoming: &[(f64, DividendType)]) -> f64 {
+        upcoming.iter()
+            .filter(|(ex_time, _)| *ex_time > time && *ex_time <= expiry)
+            .fold(spot, |escrowed, (ex_time, dividend_type)| match dividend_type {
+                DividendType::Cash(amount) => escrowed - amount * (-r * (ex_time - time)).exp(),
+                DividendType::Proportional(ratio) => escrowed * (1.0 - ratio),
+            })
+    }
+
+    // Inverse of `escrowed_spot_between`: adds back the dividends still to be paid after `time`
+    pub(crate) fn cum_dividend_spot_between(&self, escrowed: f64, r: f64, time: f64, expiry: f64, upcoming: &[(f64, DividendType)]) -> f64 {
+        upcoming.iter()
+            .rev()
+            .filter(|(ex_time, _)| *ex_time > time && *ex_time <= expiry)
+            .fold(escrowed, |spot, (ex_time, dividend_type)| match dividend_type {
+                DividendType::Cash(amount) => spot + amount * (-r * (ex_time - time)).exp(),
+                DividendType::Proportional(ratio) => spot / (1.0 - ratio),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_escrowed_spot() {
+        let valuation_datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let expiry_datetime = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let schedule = DividendSchedule::new(vec![
+            Dividend::cash(2.0, Utc.with_ymd_and_hms(2024, 7, 2, 0, 0, 0).unwrap()),
+            Dividend::proportional(0.01, Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()),
+            Dividend::cash(5.0, Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap()),
+            Dividend::cash(5.0, Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap()),
+        ]);
+
+        let escrowed_spot = schedule.escrowed_spot(100.0, 0.05, valuation_datetime, expiry_datetime);
+
+        let years_to_cash_dividend: f64 = 183.0 / 365.0;
+        let expected_spot = 100.0 * 0.99 - 2.0 * (-0.05 * years_to_cash_dividend).exp();
+        assert!((escrowed_spot - expected_spot).abs() < 1e-12, "Escrowed spot {} does not match {}", escrowed_spot, expected_spot);
+    }
+
+    #[test]
+    fn test_upcoming_skips_past_dividends() {
+        let valuation_datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let schedule = DividendSchedule::new(vec![
+            Dividend::cash(1.0, Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap()),
+            Dividend::cash(1.0, Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap()),
+        ]);
+
+        assert_eq!(schedule.upcoming(valuation_datetime).len(), 1);
+    }
+
+    #[test]
+    fn test_cash_dividend_does_not_make_price_negative() {
+        assert_eq!(DividendType::Cash(5.0).apply(3.0), 0.0);
+        assert_eq!(DividendType::Proportional(0.1).apply(50.0), 45.0);
+    }
+
+    #[test]
+    fn test_invalid_dividends() {
+        let ex_datetime = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        assert!(DividendSchedule::try_new(vec![Dividend::cash(0.0, ex_datetime), Dividend::proportional(0.0, ex_datetime)]).is_ok());
+
+        let error = DividendSchedule::try_new(vec![Dividend::cash(-1.0, ex_datetime)]).err();
+        assert_eq!(error, Some(ProcessError::NegativeParameter { name: "dividend amount", value: -1.0 }));
+        assert!(matches!(DividendSchedule::try_new(vec![Dividend::cash(f64::NAN, ex_datetime)]), Err(ProcessError::NonFiniteParameter { .. })));
+        assert!(matches!(DividendSchedule::try_new(vec![Dividend::proportional(1.0, ex_datetime)]), Err(ProcessError::InvalidParameter { name: "dividend ratio", .. })));
+        assert!(DividendSchedule::try_new(vec![Dividend::proportional(-0.1, ex_datetime)]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let schedule = DividendSchedule::new(vec![
+            Dividend::cash(1.5, Utc.with_ymd_and_hms(2024, 6, 3, 0, 0, 0).unwrap()),
+            Dividend::proportional(0.02, Utc.with_ymd_and_hms(2024, 12, 2, 0, 0, 0).unwrap()),
+        ]);
+        let json = serde_json::to_string(&schedule).unwrap();
+
+        assert!(json.contains(r#"{"ex_datetime":"2024-06-03T00:00:00Z","dividend_type":{"Cash":1.5}}"#));
+        assert_eq!(serde_json::from_str::<DividendSchedule>(&json).unwrap(), schedule);
+    }
+}
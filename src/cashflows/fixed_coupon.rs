@@ -0,0 +1,38 @@
+use crate::cashflows::{CashFlow, Currency};
+use crate::time::{DayCount, Schedule};
+
+// Coupons of `notional * rate * year fraction` paid on each payment date of `schedule`
+pub fn fixed_coupon_cashflows(schedule: &Schedule, notional: f64, rate: f64, day_count: DayCount, currency: Currency) -> Vec<CashFlow> {
+    schedule.periods().iter()
+        .map(|period| CashFlow::new(notional * rate * period.year_fraction(day_count, schedule.frequency), currency, period.payment_datetime))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::time::{Calendar, Frequency, StubType};
+    use super::*;
+
+    #[test]
+    fn test_semi_annual_coupons() {
+        let schedule = Schedule::new(Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap(), Utc.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap(), Frequency::SemiAnnual, Calendar::Target);
+        let coupons = fixed_coupon_cashflows(&schedule, 1_000_000.0, 0.04, DayCount::ThirtyE360, Currency::EUR);
+
+        assert_eq!(coupons.len(), 4);
+        assert!(coupons.iter().all(|coupon| coupon.amount == 20_000.0 && coupon.currency == Currency::EUR));
+        assert_eq!(coupons[1].settlement_datetime, Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_short_front_stub_coupon() {
+        let schedule = Schedule::new(Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap(), Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap(), Frequency::SemiAnnual, Calendar::Target)
+            .with_stub_type(StubType::ShortFront);
+        let coupons = fixed_coupon_cashflows(&schedule, 100.0, 0.05, DayCount::ActualActualIcma, Currency::EUR);
+
+        // 122 days accrued out of the 182 day reference period from 15 January to 15 July
+        assert!((coupons[0].amount - 100.0 * 0.05 * 122.0 / (2.0 * 182.0)).abs() < 1e-12);
+        assert_eq!(coupons[1].amount, 2.5);
+    }
+}
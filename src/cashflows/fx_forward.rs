@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+
+use crate::cashflows::{CashFlow, CashFlowSet, Currency, CurrencyPair, FxRates};
+use crate::curves::DiscountCurve;
+use crate::error::QuantError;
+
+// Outright FX forwards from spot rates and one discount curve per currency, by covered interest parity
+pub struct FxForwardRates<'a> {
+    pub spot_rates: &'a FxRates,
+    pub spot_datetime: DateTime<Utc>, // Date the spot rates settle on, usually two business days after trade
+    discount_curves: Vec<(Currency, &'a dyn DiscountCurve)>,
+}
+
+// Forward points are quoted in pips of the market pair, hundredths for yen quotes and ten thousandths otherwise
+fn pip_multiplier(pair: CurrencyPair) -> f64 {
+    if pair.quote == Currency::JPY { 100.0 } else { 10_000.0 }
+}
+
+impl<'a> FxForwardRates<'a> {
+    pub fn new(spot_rates: &'a FxRates, spot_datetime: DateTime<Utc>) -> Self {
+        FxForwardRates { spot_rates, spot_datetime, discount_curves: Vec::new() }
+    }
+
+    pub fn with_discount_curve(mut self, currency: Currency, discount_curve: &'a dyn DiscountCurve) -> Self {
+        self.discount_curves.retain(|(existing, _)| *existing != currency);
+        self.discount_curves.push((currency, discount_curve));
+        self
+    }
+
+    // Discount factor from the spot date to `datetime` in `currency`
+    fn spot_discount_factor(&self, currency: Currency, datetime: DateTime<Utc>) -> Result<f64, QuantError> {
+        let (_, curve) = self.discount_curves.iter()
+            .find(|(existing, _)| *existing == currency)
+            .ok_or(QuantError::MissingDiscountCurve { currency })?;
+
+        Ok(curve.discount_factor(datetime) / curve.discount_factor(self.spot_datetime))
+    }
+
+    // Units of `to` per unit of `from` for delivery on `datetime`: F = S * P_from(spot, T) / P_to(spot, T)
+    pub fn forward_rate(&self, from: Currency, to: Currency, datetime: DateTime<Utc>) -> Result<f64, QuantError> {
+        if from == to {
+            return Ok(1.0);
+        }
+        let spot_rate = self.spot_rates.rate(from, to)?;
+
+        Ok(spot_rate * self.spot_discount_factor(from, datetime)? / self.spot_discount_factor(to, datetime)?)
+    }
+
+    pub fn forward_points(&self, first: Currency, second: Currency, datetime: DateTime<Utc>) -> Result<f64, QuantError> {
+        let pair = CurrencyPair::market_convention(first, second);
+        let forward_rate = self.forward_rate(pair.base, pair.quote, datetime)?;
+        let spot_rate = self.spot_rates.rate(pair.base, pair.quote)?;
+
+        Ok((forward_rate - spot_rate) * pip_multiplier(pair))
+    }
+
+    // Converts at the forward rate for the cashflow's own settlement date
+    pub fn convert(&self, cashflow: &CashFlow, currency: Currency) -> Result<CashFlow, QuantError> {
+        Ok(cashflow.convert_to(currency, self.forward_rate(cashflow.currency, currency, cashflow.settlement_datetime)?))
+    }
+
+    pub fn convert_set(&self, cashflows: &CashFlowSet, currency: Currency) -> Result<CashFlowSet, QuantError> {
+        cashflows.iter().map(|cashflow| self.convert(cashflow, currency)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::FlatDiscountCurve;
+    use crate::time::{date, DayCount};
+    use super::*;
+
+    #[test]
+    fn test_covered_interest_parity() {
+        let spot_rates = FxRates::new(Currency::USD).with_quote(CurrencyPair::new(Currency::EUR, Currency::USD), 1.10).unwrap();
+        let eur_curve = FlatDiscountCurve::new(date(2024, 1, 1), 0.03, DayCount::Actual365Fixed);
+        let usd_curve = FlatDiscountCurve::new(date(2024, 1, 1), 0.05, DayCount::Actual365Fixed);
+        let forwards = FxForwardRates::new(&spot_rates, date(2024, 1, 3))
+            .with_discount_curve(Currency::EUR, &eur_curve)
+            .with_discount_curve(Currency::USD, &usd_curve);
+
+        let one_year_after_spot = date(2025, 1, 2);
+        let forward_rate = forwards.forward_rate(Currency::EUR, Currency::USD, one_year_after_spot).unwrap();
+        assert!((forward_rate - 1.10 * 0.02f64.exp()).abs() < 1e-12);
+
+        let inverse_rate = forwards.forward_rate(Currency::USD, Currency::EUR, one_year_after_spot).unwrap();
+        assert!((inverse_rate * forward_rate - 1.0).abs() < 1e-12);
+
+        let points = forwards.forward_points(Currency::USD, Currency::EUR, one_year_after_spot).unwrap();
+        assert!((points - (forward_rate - 1.10) * 10_000.0).abs() < 1e-8);
+        assert!((forwards.forward_rate(Currency::EUR, Currency::USD, date(2024, 1, 3)).unwrap() - 1.10).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_convert_at_forward_rate() {
+        let spot_rates = FxRates::new(Currency::USD).with_quote(CurrencyPair::new(Currency::USD, Currency::JPY), 150.0).unwrap();
+        let usd_curve = FlatDiscountCurve::new(date(2024, 1, 1), 0.05, DayCount::Actual365Fixed);
+        let jpy_curve = FlatDiscountCurve::new(date(2024, 1, 1), 0.0, DayCount::Actual365Fixed);
+        let forwards = FxForwardRates::new(&spot_rates, date(2024, 1, 1))
+            .with_discount_curve(Currency::USD, &usd_curve)
+            .with_discount_curve(Currency::JPY, &jpy_curve);
+
+        let cashflow = CashFlow::new(1_000_000.0, Currency::JPY, date(2025, 1, 1));
+        let converted = forwards.convert(&cashflow, Currency::USD).unwrap();
+
+        assert!((converted.amount - 1_000_000.0 / (150.0 * (-0.05f64 * 366.0 / 365.0).exp())).abs() < 1e-8);
+        assert_eq!(converted.settlement_datetime, cashflow.settlement_datetime);
+        assert!(forwards.convert(&cashflow, Currency::EUR).is_err());
+        assert!(forwards.forward_points(Currency::USD, Currency::JPY, date(2025, 1, 1)).unwrap() < 0.0);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::cashflows::{CashFlow, CashFlowSet, Currency};
+use crate::error::{check_positive_input, QuantError};
+
+// Units of `quote` currency paid for one unit of `base` currency, e.g. EUR/USD = 1.08
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CurrencyPair {
+    pub base: Currency,
+    pub quote: Currency,
+}
+
+// Ranking deciding which currency of a pair is the base in market quotes
+fn quoting_priority(currency: Currency) -> usize {
+    const PRIORITY: [Currency; 19] = [
+        Currency::EUR, Currency::GBP, Currency::AUD, Currency::NZD, Currency::USD, Currency::CAD, Currency::CHF,
+        Currency::NOK, Currency::SEK, Currency::DKK, Currency::PLN, Currency::CZK, Currency::HUF,
+        Currency::SGD, Currency::HKD, Currency::CNH, Currency::MXN, Currency::ZAR, Currency::JPY,
+    ];
+    PRIORITY.iter().position(|ranked| *ranked == currency).unwrap_or(PRIORITY.len())
+}
+
+impl CurrencyPair {
+    pub fn new(base: Currency, quote: Currency) -> Self {
+        CurrencyPair { base, quote }
+    }
+
+    pub fn inverse(&self) -> CurrencyPair {
+        CurrencyPair::new(self.quote, self.base)
+    }
+
+    // The pair in the direction the market quotes it, e.g. USD/JPY rather than JPY/USD
+    pub fn market_convention(first: Currency, second: Currency) -> CurrencyPair {
+        if quoting_priority(second) < quoting_priority(first) { CurrencyPair::new(second, first) } else { CurrencyPair::new(first, second) }
+    }
+
+    pub fn is_market_convention(&self) -> bool {
+        *self == CurrencyPair::market_convention(self.base, self.quote)
+    }
+}
+
+// Spot FX quotes with crosses triangulated through a pivot currency
+#[derive(Clone, Debug)]
+pub struct FxRates {
+    pub pivot: Currency,
+    quotes: Vec<(CurrencyPair, f64)>,
+}
+
+impl FxRates {
+    pub fn new(pivot: Currency) -> Self {
+        FxRates { pivot, quotes: Vec::new() }
+    }
+
+    pub fn with_quote(mut self, pair: CurrencyPair, rate: f64) -> Result<Self, QuantError> {
+        self.set_quote(pair, rate)?;
+        Ok(self)
+    }
+
+    // Stores the quote in the direction given, replacing any quote for the same pair in either direction
+    pub fn set_quote(&mut self, pair: CurrencyPair, rate: f64) -> Result<(), QuantError> {
+        check_positive_input("fx rate", rate)?;
+        self.quotes.retain(|(quoted, _)| *quoted != pair && *quoted != pair.inverse());
+        self.quotes.push((pair, rate));
+        Ok(())
+    }
+
+    fn quoted_rate(&self, from: Currency, to: Currency) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+        self.quotes.iter().find_map(|(pair, rate)| {
+            if *pair == CurrencyPair::new(from, to) {
+                Some(*rate)
+            } else if *pair == CurrencyPair::new(to, from) {
+                Some(1.0 / rate)
+            } else {
+                None
+            }
+        })
+    }
+
+    // Units of `to` per unit of `from`, from a direct or inverted quote or else through the pivot currency
+    pub fn rate(&self, from: Currency, to: Currency) -> Result<f64, QuantError> {
+        self.quoted_rate(from, to)
+            .or_else(|| Some(self.quoted_rate(from, self.pivot)? * self.quoted_rate(self.pivot, to)?))
+            .ok_or(QuantError::MissingFxRate { from, to })
+    }
+
+    pub fn market_quote(&self, first: Currency, second: Currency) -> Result<(CurrencyPair, f64), QuantError> {
+        let pair = CurrencyPair::market_convention(first, second);
+        Ok((pair, self.rate(pair.base, pair.quote)?))
+    }
+
+    pub fn convert(&self, cashflow: &CashFlow, currency: Currency) -> Result<CashFlow, QuantError> {
+        Ok(cashflow.convert_to(currency, self.rate(cashflow.currency, currency)?))
+    }
+
+    pub fn convert_set(&self, cashflows: &CashFlowSet, currency: Currency) -> Result<CashFlowSet, QuantError> {
+        cashflows.iter().map(|cashflow| self.convert(cashflow, currency)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn create_rates() -> FxRates {
+        FxRates::new(Currency::USD)
+            .with_quote(CurrencyPair::new(Currency::EUR, Currency::USD), 1.08).unwrap()
+            .with_quote(CurrencyPair::new(Currency::USD, Currency::JPY), 150.0).unwrap()
+            .with_quote(CurrencyPair::new(Currency::AUD, Currency::USD), 0.65).unwrap()
+    }
+
+    #[test]
+    fn test_market_convention() {
+        assert_eq!(CurrencyPair::market_convention(Currency::JPY, Currency::USD), CurrencyPair::new(Currency::USD, Currency::JPY));
+        assert_eq!(CurrencyPair::market_convention(Currency::USD, Currency::EUR), CurrencyPair::new(Currency::EUR, Currency::USD));
+        assert_eq!(CurrencyPair::market_convention(Currency::AUD, Currency::EUR), CurrencyPair::new(Currency::EUR, Currency::AUD));
+        assert!(!CurrencyPair::new(Currency::CHF, Currency::USD).is_market_convention());
+    }
+
+    #[test]
+    fn test_inversion_and_triangulation() {
+        let rates = create_rates();
+
+        assert_eq!(rates.rate(Currency::USD, Currency::EUR).unwrap(), 1.0 / 1.08);
+        assert!((rates.rate(Currency::EUR, Currency::JPY).unwrap() - 162.0).abs() < 1e-12);
+
+        let (pair, rate) = rates.market_quote(Currency::AUD, Currency::EUR).unwrap();
+        assert_eq!(pair, CurrencyPair::new(Currency::EUR, Currency::AUD));
+        assert!((rate - 1.08 / 0.65).abs() < 1e-12);
+
+        assert_eq!(rates.rate(Currency::EUR, Currency::CHF), Err(QuantError::MissingFxRate { from: Currency::EUR, to: Currency::CHF }));
+    }
+
+    #[test]
+    fn test_quotes_replace_either_direction() {
+        let mut rates = create_rates();
+        rates.set_quote(CurrencyPair::new(Currency::USD, Currency::EUR), 0.9).unwrap();
+
+        assert!((rates.rate(Currency::EUR, Currency::USD).unwrap() - 1.0 / 0.9).abs() < 1e-12);
+        assert!(rates.set_quote(CurrencyPair::new(Currency::USD, Currency::EUR), -1.0).is_err());
+    }
+
+    #[test]
+    fn test_convert_set() {
+        let settlement_datetime = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let cashflows = CashFlowSet::new(vec![
+            CashFlow::new(100.0, Currency::EUR, settlement_datetime),
+            CashFlow::new(15000.0, Currency::JPY, settlement_datetime),
+        ]);
+
+        let converted = create_rates().convert_set(&cashflows, Currency::USD).unwrap();
+
+        assert!((converted.total(Currency::USD) - 208.0).abs() < 1e-10);
+        assert_eq!(converted.currencies(), vec![Currency::USD]);
+    }
+}
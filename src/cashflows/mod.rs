@@ -2,3 +2,17 @@ pub mod cashflow;
 pub use cashflow::CashFlow;
 pub mod currency;
 pub use self::currency::Currency;
+pub mod dividend;
+pub use self::dividend::{Dividend, DividendSchedule, DividendType};
+pub mod fixed_coupon;
+pub use self::fixed_coupon::fixed_coupon_cashflows;
+pub mod cashflow_set;
+pub use self::cashflow_set::CashFlowSet;
+pub mod fx_rates;
+pub use self::fx_rates::{CurrencyPair, FxRates};
+pub mod fx_forward;
+pub use self::fx_forward::FxForwardRates;
+pub mod rounding;
+pub use self::rounding::{round_amount, RoundingMode, SettlementCashFlow};
+pub mod analytics;
+pub use self::analytics::{convexity, dv01, irr, macaulay_duration, modified_duration, npv, npv_with_curve, xirr};
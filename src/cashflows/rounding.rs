@@ -0,0 +1,100 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::cashflows::{CashFlow, Currency};
+use crate::error::QuantError;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    HalfEven, // Banker's rounding, ties go to the even digit
+    HalfUp, // Ties go away from zero
+    Truncate,
+}
+
+impl RoundingMode {
+    fn strategy(&self) -> RoundingStrategy {
+        match self {
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::Truncate => RoundingStrategy::ToZero,
+        }
+    }
+}
+
+// Rounds to the currency's minor units, after removing the binary noise of the float so that 2.675 rounds as written
+pub fn round_amount(amount: f64, currency: Currency, rounding_mode: RoundingMode) -> Result<Decimal, QuantError> {
+    let decimal = Decimal::from_f64(amount)
+        .ok_or(QuantError::InvalidInput { name: "amount", value: amount, reason: "must be finite and within decimal range" })?;
+
+    Ok(decimal.round_dp_with_strategy(currency.minor_units() as u32, rounding_mode.strategy()))
+}
+
+// Cashflow with an exact decimal amount in the currency's minor units, as paid on settlement
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SettlementCashFlow {
+    pub amount: Decimal,
+    pub currency: Currency,
+    pub settlement_datetime: DateTime<Utc>,
+}
+
+impl SettlementCashFlow {
+    pub fn new(amount: Decimal, currency: Currency, settlement_datetime: DateTime<Utc>) -> Self {
+        SettlementCashFlow { amount, currency, settlement_datetime }
+    }
+
+    pub fn from_cashflow(cashflow: &CashFlow, rounding_mode: RoundingMode) -> Result<Self, QuantError> {
+        Ok(SettlementCashFlow::new(round_amount(cashflow.amount, cashflow.currency, rounding_mode)?, cashflow.currency, cashflow.settlement_datetime))
+    }
+
+    pub fn to_cashflow(&self) -> CashFlow {
+        CashFlow::new(self.amount.to_f64().unwrap_or(f64::NAN), self.currency, self.settlement_datetime)
+    }
+}
+
+impl fmt::Display for SettlementCashFlow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:.*}", self.currency, self.currency.minor_units() as usize, self.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn decimal(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        assert_eq!(round_amount(2.675, Currency::USD, RoundingMode::HalfUp).unwrap(), decimal("2.68"));
+        assert_eq!(round_amount(2.665, Currency::USD, RoundingMode::HalfEven).unwrap(), decimal("2.66"));
+        assert_eq!(round_amount(-2.675, Currency::USD, RoundingMode::HalfUp).unwrap(), decimal("-2.68"));
+        assert_eq!(round_amount(2.679, Currency::USD, RoundingMode::Truncate).unwrap(), decimal("2.67"));
+    }
+
+    #[test]
+    fn test_minor_units_drive_precision() {
+        assert_eq!(round_amount(1234.5, Currency::JPY, RoundingMode::HalfEven).unwrap(), decimal("1234"));
+        assert_eq!(round_amount(1.23456, Currency::KWD, RoundingMode::HalfUp).unwrap(), decimal("1.235"));
+        assert!(round_amount(f64::NAN, Currency::USD, RoundingMode::HalfUp).is_err());
+    }
+
+    #[test]
+    fn test_settlement_cashflow() {
+        let settlement_datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let cashflow = CashFlow::new(0.1 + 0.2, Currency::EUR, settlement_datetime);
+        let settlement = SettlementCashFlow::from_cashflow(&cashflow, RoundingMode::HalfEven).unwrap();
+
+        assert_eq!(settlement.amount, decimal("0.30"));
+        assert_eq!(settlement.to_string(), "EUR 0.30");
+        assert_eq!(settlement.to_cashflow().amount, 0.3);
+    }
+}
@@ -0,0 +1,498 @@
+use chrono::{DateTime, Utc};
+
+use crate::cashflows::{CashFlow, Currency};
+use crate::curves::{DiscountCurve, InterpolatedDiscountCurve, Interpolation, RateIndex};
+use crate::error::{check_finite_input, check_positive_input, QuantError};
+use crate::math::optimization::{levenberg_marquardt, LevenbergMarquardtSettings};
+use crate::time::{AccrualPeriod, DayCount, Schedule};
+
+// Zero rates outside this range are treated as a failure to reprice rather than a curve
+const ZERO_RATE_BOUNDS: (f64, f64) = (-0.5, 1.0);
+
+// Quoted instrument that pins down one curve node. Rates are decimals, futures are quoted as 100 minus the rate in percent.
+#[derive(Clone, Debug)]
+pub enum RateHelper {
+    Deposit { start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, rate: f64, day_count: DayCount },
+    Fra { start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, rate: f64, day_count: DayCount },
+    Future { start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, price: f64, convexity_adjustment: f64, day_count: DayCount }, // Forward rate is the futures rate minus the convexity adjustment
+    Swap { fixed_schedule: Schedule, rate: f64, fixed_day_count: DayCount, index: Option<RateIndex> }, // Par swap, with floating periods following the index tenor or the fixed periods
+}
+
+impl RateHelper {
+    pub fn deposit(start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, rate: f64, day_count: DayCount) -> Self {
+        RateHelper::Deposit { start_datetime, end_datetime, rate, day_count }
+    }
+
+    pub fn fra(start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, rate: f64, day_count: DayCount) -> Self {
+        RateHelper::Fra { start_datetime, end_datetime, rate, day_count }
+    }
+
+    pub fn future(start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, price: f64, convexity_adjustment: f64, day_count: DayCount) -> Self {
+        RateHelper::Future { start_datetime, end_datetime, price, convexity_adjustment, day_count }
+    }
+
+    pub fn swap(fixed_schedule: Schedule, rate: f64, fixed_day_count: DayCount) -> Self {
+        RateHelper::Swap { fixed_schedule, rate, fixed_day_count, index: None }
+    }
+
+    // Swap against a floating leg on `index`, e.g. annual fixed against EURIBOR 6M or an overnight index swap
+    pub fn index_swap(fixed_schedule: Schedule, rate: f64, fixed_day_count: DayCount, index: RateIndex) -> Self {
+        RateHelper::Swap { fixed_schedule, rate, fixed_day_count, index: Some(index) }
+    }
+
+    // Market quote in the instrument's own units: a rate, or a price for futures
+    pub fn quote(&self) -> f64 {
+        match self {
+            RateHelper::Deposit { rate, .. } | RateHelper::Fra { rate, .. } | RateHelper::Swap { rate, .. } => *rate,
+            RateHelper::Future { price, .. } => *price,
+        }
+    }
+
+    // Simply compounded forward rate implied by the quote over the accrual period
+    fn forward_rate(&self) -> f64 {
+        match self {
+            RateHelper::Future { price, convexity_adjustment, .. } => (100.0 - price) / 100.0 - convexity_adjustment,
+            _ => self.quote(),
+        }
+    }
+
+    // Every accrual period must have a positive length, which also rules out swaps whose schedule has no periods
+    fn validate(&self) -> Result<(), QuantError> {
+        let year_fractions = match self {
+            RateHelper::Deposit { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Fra { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Future { start_datetime, end_datetime, day_count, .. } => vec![day_count.year_fraction(*start_datetime, *end_datetime)],
+            RateHelper::Swap { fixed_schedule, fixed_day_count, .. } => match fixed_schedule.periods() {
+                periods if periods.is_empty() => vec![fixed_day_count.year_fraction(fixed_schedule.start_datetime, fixed_schedule.end_datetime)],
+                periods => periods.iter().map(|period| period.year_fraction(*fixed_day_count, fixed_schedule.frequency)).collect(),
+            },
+        };
+
+        check_finite_input("rate helper quote", self.quote())?;
+        year_fractions.into_iter().try_for_each(|year_fraction| check_positive_input("accrual period", year_fraction))
+    }
+
+    // Latest cash flow date, where the helper places its curve node
+    pub fn pillar_datetime(&self) -> DateTime<Utc> {
+        match self {
+            RateHelper::Deposit { end_datetime, .. } | RateHelper::Fra { end_datetime, .. } | RateHelper::Future { end_datetime, .. } => *end_datetime,
+            RateHelper::Swap { fixed_schedule, .. } => {
+                let periods = fixed_schedule.periods();
+                let last = periods.last().unwrap();
+                last.payment_datetime.max(last.accrual_end)
+            }
+        }
+    }
+
+    // Cash flows of a unit notional that lends at the quoted rate, with zero value on a single curve that reprices the helper.
+    // Swaps are represented by their fixed leg plus the exchange of notionals that replicates a floating leg on that curve.
+    pub fn cashflows(&self, currency: Currency) -> Vec<CashFlow> {
+        match self {
+            RateHelper::Deposit { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Fra { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Future { start_datetime, end_datetime, day_count, .. } => {
+                let year_fraction = day_count.year_fraction(*start_datetime, *end_datetime);
+                vec![
+                    CashFlow::new(-1.0, currency, *start_datetime),
+                    CashFlow::new(1.0 + self.forward_rate() * year_fraction, currency, *end_datetime),
+                ]
+            }
+            RateHelper::Swap { fixed_schedule, rate, fixed_day_count, .. } => {
+                let periods = fixed_schedule.periods();
+                let mut cashflows = vec![CashFlow::new(-1.0, currency, periods[0].accrual_start)];
+                cashflows.extend(periods.iter().map(|period| {
+                    CashFlow::new(rate * period.year_fraction(*fixed_day_count, fixed_schedule.frequency), currency, period.payment_datetime)
+                }));
+                cashflows.push(CashFlow::new(1.0, currency, periods.last().unwrap().accrual_end));
+                cashflows
+            }
+        }
+    }
+
+    fn floating_periods(fixed_schedule: &Schedule, index: Option<RateIndex>) -> Vec<AccrualPeriod> {
+        match index.and_then(|index| index.tenor()) {
+            Some(frequency) => Schedule { frequency, ..fixed_schedule.clone() }.periods(),
+            None => fixed_schedule.periods(),
+        }
+    }
+
+    // Projected floating coupons per unit notional, each the forward growth of the projection curve over its accrual period
+    fn floating_leg_value(fixed_schedule: &Schedule, index: Option<RateIndex>, projection_curve: &dyn DiscountCurve, discount_curve: &dyn DiscountCurve) -> f64 {
+        RateHelper::floating_periods(fixed_schedule, index).iter()
+            .map(|period| (projection_curve.discount_factor(period.accrual_start) / projection_curve.discount_factor(period.accrual_end) - 1.0) * discount_curve.discount_factor(period.payment_datetime))
+            .sum()
+    }
+
+    fn annuity(fixed_schedule: &Schedule, fixed_day_count: DayCount, discount_curve: &dyn DiscountCurve) -> f64 {
+        fixed_schedule.periods().iter()
+            .map(|period| period.year_fraction(fixed_day_count, fixed_schedule.frequency) * discount_curve.discount_factor(period.payment_datetime))
+            .sum()
+    }
+
+    // Value per unit notional of receiving the quoted rate, with forwards from `projection_curve` discounted on
+    // `discount_curve`. Zero for curves that reprice the helper.
+    pub fn value(&self, projection_curve: &dyn DiscountCurve, discount_curve: &dyn DiscountCurve) -> f64 {
+        match self {
+            RateHelper::Deposit { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Fra { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Future { start_datetime, end_datetime, day_count, .. } => {
+                let year_fraction = day_count.year_fraction(*start_datetime, *end_datetime);
+                let forward_growth = projection_curve.discount_factor(*start_datetime) / projection_curve.discount_factor(*end_datetime) - 1.0;
+                (self.forward_rate() * year_fraction - forward_growth) * discount_curve.discount_factor(*end_datetime)
+            }
+            RateHelper::Swap { fixed_schedule, rate, fixed_day_count, index } => {
+                rate * RateHelper::annuity(fixed_schedule, *fixed_day_count, discount_curve)
+                    - RateHelper::floating_leg_value(fixed_schedule, *index, projection_curve, discount_curve)
+            }
+        }
+    }
+
+    // Change in `value` per unit change of the quote, which it depends on linearly
+    fn quote_sensitivity(&self, discount_curve: &dyn DiscountCurve) -> f64 {
+        match self {
+            RateHelper::Deposit { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Fra { start_datetime, end_datetime, day_count, .. } => day_count.year_fraction(*start_datetime, *end_datetime) * discount_curve.discount_factor(*end_datetime),
+            RateHelper::Future { start_datetime, end_datetime, day_count, .. } => -day_count.year_fraction(*start_datetime, *end_datetime) * discount_curve.discount_factor(*end_datetime) / 100.0,
+            RateHelper::Swap { fixed_schedule, fixed_day_count, .. } => RateHelper::annuity(fixed_schedule, *fixed_day_count, discount_curve),
+        }
+    }
+
+    // Quote that would reprice the helper on a single curve
+    pub fn implied_quote(&self, curve: &dyn DiscountCurve) -> f64 {
+        self.implied_quote_with_discount_curve(curve, curve)
+    }
+
+    pub fn implied_quote_with_discount_curve(&self, projection_curve: &dyn DiscountCurve, discount_curve: &dyn DiscountCurve) -> f64 {
+        match self {
+            RateHelper::Deposit { start_datetime, end_datetime, day_count, .. }
+            | RateHelper::Fra { start_datetime, end_datetime, day_count, .. } => {
+                let year_fraction = day_count.year_fraction(*start_datetime, *end_datetime);
+                (projection_curve.discount_factor(*start_datetime) / projection_curve.discount_factor(*end_datetime) - 1.0) / year_fraction
+            }
+            RateHelper::Future { start_datetime, end_datetime, convexity_adjustment, day_count, .. } => {
+                let year_fraction = day_count.year_fraction(*start_datetime, *end_datetime);
+                let forward = (projection_curve.discount_factor(*start_datetime) / projection_curve.discount_factor(*end_datetime) - 1.0) / year_fraction;
+                100.0 * (1.0 - forward - convexity_adjustment)
+            }
+            RateHelper::Swap { fixed_schedule, fixed_day_count, index, .. } => {
+                RateHelper::floating_leg_value(fixed_schedule, *index, projection_curve, discount_curve) / RateHelper::annuity(fixed_schedule, *fixed_day_count, discount_curve)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BootstrapMethod {
+    Sequential, // Solve each node in pillar order, repeating the sweep until nodes settle for non-local interpolations
+    Global, // Fit all nodes at once by least squares on the instrument values
+}
+
+#[derive(Clone, Debug)]
+pub struct Bootstrapper {
+    pub reference_datetime: DateTime<Utc>,
+    pub currency: Currency, // Of the generated helper cash flows
+    pub day_count: DayCount,
+    pub interpolation: Interpolation,
+    pub method: BootstrapMethod,
+    pub tolerance: f64, // On the value of each helper per unit notional
+    pub max_iterations: usize, // Sweeps for the sequential method, optimizer iterations for the global one
+}
+
+#[derive(Clone, Debug)]
+pub struct BootstrapResult {
+    pub curve: InterpolatedDiscountCurve,
+    pub reprice_errors: Vec<f64>, // Implied minus market quote for each helper, in the input order, valued as in the solve
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+struct PreparedHelper<'h> {
+    time: f64,
+    helper: &'h RateHelper,
+    cashflows: Vec<CashFlow>,
+}
+
+impl Bootstrapper {
+    pub fn new(reference_datetime: DateTime<Utc>, currency: Currency) -> Self {
+        Bootstrapper {
+            reference_datetime,
+            currency,
+            day_count: DayCount::Actual365Fixed,
+            interpolation: Interpolation::LogLinearDiscount,
+            method: BootstrapMethod::Sequential,
+            tolerance: 1e-12,
+            max_iterations: 100,
+        }
+    }
+
+    pub fn with_day_count(mut self, day_count: DayCount) -> Self {
+        self.day_count = day_count;
+        self
+    }
+
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn with_method(mut self, method: BootstrapMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn curve(&self, times: &[f64], zero_rates: &[f64]) -> Result<InterpolatedDiscountCurve, QuantError> {
+        let discount_factors = times.iter().zip(zero_rates.iter()).map(|(t, zero_rate)| (-zero_rate * t).exp()).collect();
+        InterpolatedDiscountCurve::from_times(self.reference_datetime, times.to_vec(), discount_factors, self.day_count, self.interpolation)
+    }
+
+    // Without a separate discount curve the curve being built also discounts, and the helper's cash flows price it
+    fn value(curve: &InterpolatedDiscountCurve, helper: &PreparedHelper, discount_curve: Option<&dyn DiscountCurve>) -> f64 {
+        match discount_curve {
+            Some(discount_curve) => helper.helper.value(curve, discount_curve),
+            None => Bootstrapper::cashflow_value(curve, &helper.cashflows),
+        }
+    }
+
+    fn cashflow_value(curve: &InterpolatedDiscountCurve, cashflows: &[CashFlow]) -> f64 {
+        cashflows.iter().fold(0.0, |value, cashflow| value + curve.present_value(cashflow).amount)
+    }
+
+    // Bisection safeguarded secant search for the zero rate of node `i` that gives the helper zero value
+    fn solve_node(&self, helper: &PreparedHelper, discount_curve: Option<&dyn DiscountCurve>, i: usize, times: &[f64], zero_rates: &mut [f64]) -> Result<bool, QuantError> {
+        let value_at = |zero_rate: f64, zero_rates: &mut [f64]| -> Result<f64, QuantError> {
+            zero_rates[i] = zero_rate;
+            Ok(Bootstrapper::value(&self.curve(times, zero_rates)?, helper, discount_curve))
+        };
+
+        // Lending at the quote is worth more the lower the curve, so the value decreases with the zero rate
+        let (mut lower, mut upper) = ZERO_RATE_BOUNDS;
+        let initial_guess = zero_rates[i];
+        if value_at(lower, zero_rates)? < 0.0 || value_at(upper, zero_rates)? > 0.0 {
+            zero_rates[i] = initial_guess;
+            return Ok(false);
+        }
+
+        let (mut previous, mut previous_value) = (upper, value_at(upper, zero_rates)?);
+        let mut zero_rate = initial_guess.clamp(lower, upper);
+        for _ in 0..self.max_iterations.max(100) {
+            let value = value_at(zero_rate, zero_rates)?;
+            if value.abs() < self.tolerance {
+                return Ok(true);
+            }
+            if value > 0.0 { lower = zero_rate } else { upper = zero_rate }
+
+            let secant_step = zero_rate - value * (zero_rate - previous) / (value - previous_value);
+            (previous, previous_value) = (zero_rate, value);
+            zero_rate = if secant_step > lower && secant_step < upper { secant_step } else { 0.5 * (lower + upper) };
+        }
+
+        value_at(zero_rate, zero_rates)?;
+        Ok(false)
+    }
+
+    // Single curve bootstrap, where the curve both projects and discounts
+    pub fn bootstrap(&self, helpers: &[RateHelper]) -> Result<BootstrapResult, QuantError> {
+        self.solve(helpers, None)
+    }
+
+    // Dual curve bootstrap of the projection curve of an index, discounting on a given curve such as the OIS curve
+    pub fn bootstrap_forward_curve(&self, helpers: &[RateHelper], discount_curve: &dyn DiscountCurve) -> Result<BootstrapResult, QuantError> {
+        self.solve(helpers, Some(discount_curve))
+    }
+
+    fn solve(&self, helpers: &[RateHelper], discount_curve: Option<&dyn DiscountCurve>) -> Result<BootstrapResult, QuantError> {
+        if helpers.is_empty() {
+            return Err(QuantError::EmptyInput { name: "rate helpers" });
+        }
+        for helper in helpers {
+            helper.validate()?;
+        }
+
+        let mut prepared: Vec<PreparedHelper> = helpers.iter()
+            .map(|helper| PreparedHelper {
+                time: self.day_count.year_fraction(self.reference_datetime, helper.pillar_datetime()),
+                helper,
+                cashflows: helper.cashflows(self.currency),
+            })
+            .collect();
+        prepared.sort_by(|a, b| a.time.total_cmp(&b.time));
+        let times: Vec<f64> = prepared.iter().map(|helper| helper.time).collect();
+
+        // Starting from a flat curve also validates the pillar ordering before any solving
+        let mut zero_rates = vec![0.02; times.len()];
+        self.curve(&times, &zero_rates)?;
+
+        let (iterations, converged) = match self.method {
+            BootstrapMethod::Sequential => {
+                let mut sweeps = 0;
+                let mut converged = false;
+                while sweeps < self.max_iterations && !converged {
+                    let previous = zero_rates.clone();
+                    let mut solved = true;
+                    for (i, helper) in prepared.iter().enumerate() {
+                        // Nodes not solved yet start from the last solved rate
+                        if sweeps == 0 && i > 0 {
+                            zero_rates[i] = zero_rates[i - 1];
+                        }
+                        solved &= self.solve_node(helper, discount_curve, i, &times, &mut zero_rates)?;
+                    }
+                    sweeps += 1;
+
+                    let largest_change = zero_rates.iter().zip(previous.iter()).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max);
+                    converged = solved && (largest_change < 1e-12 || self.is_repriced(&prepared, discount_curve, &times, &zero_rates)?);
+                }
+                (sweeps, converged)
+            }
+            BootstrapMethod::Global => {
+                let residuals = |zero_rates: &[f64]| -> Vec<f64> {
+                    match self.curve(&times, zero_rates) {
+                        Ok(curve) => prepared.iter().map(|helper| Bootstrapper::value(&curve, helper, discount_curve)).collect(),
+                        Err(_) => vec![f64::INFINITY; prepared.len()],
+                    }
+                };
+                let bounds = vec![ZERO_RATE_BOUNDS; times.len()];
+                let settings = LevenbergMarquardtSettings { max_iterations: self.max_iterations, ..LevenbergMarquardtSettings::default() };
+                let result = levenberg_marquardt(residuals, &zero_rates, &bounds, settings);
+                zero_rates = result.parameters;
+                (result.iterations, self.is_repriced(&prepared, discount_curve, &times, &zero_rates)?)
+            }
+        };
+
+        let curve = self.curve(&times, &zero_rates)?;
+        // Errors come from the same valuation as the solve. The cash flows of single curve helpers have a value linear in
+        // the quote, so the quote change that gives them zero value is their value over its sensitivity.
+        let reprice_errors = helpers.iter()
+            .map(|helper| match discount_curve {
+                Some(discount_curve) => helper.implied_quote_with_discount_curve(&curve, discount_curve) - helper.quote(),
+                None => -Bootstrapper::cashflow_value(&curve, &helper.cashflows(self.currency)) / helper.quote_sensitivity(&curve),
+            })
+            .collect();
+
+        Ok(BootstrapResult { curve, reprice_errors, iterations, converged })
+    }
+
+    fn is_repriced(&self, helpers: &[PreparedHelper], discount_curve: Option<&dyn DiscountCurve>, times: &[f64], zero_rates: &[f64]) -> Result<bool, QuantError> {
+        let curve = self.curve(times, zero_rates)?;
+        Ok(helpers.iter().all(|helper| Bootstrapper::value(&curve, helper, discount_curve).abs() < self.tolerance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::{date, Calendar, Frequency};
+    use super::*;
+
+    fn create_helpers() -> Vec<RateHelper> {
+        let swap = |years: i32, rate: f64| {
+            let schedule = Schedule::new(date(2024, 1, 3), date(2024 + years, 1, 3), Frequency::Annual, Calendar::Target);
+            RateHelper::swap(schedule, rate, DayCount::Thirty360Us)
+        };
+
+        vec![
+            RateHelper::deposit(date(2024, 1, 3), date(2024, 4, 3), 0.0390, DayCount::Actual360),
+            RateHelper::fra(date(2024, 4, 3), date(2024, 7, 3), 0.0385, DayCount::Actual360),
+            RateHelper::future(date(2024, 7, 3), date(2024, 10, 3), 96.20, 0.0002, DayCount::Actual360),
+            swap(2, 0.0360),
+            swap(3, 0.0350),
+            swap(5, 0.0345),
+            swap(10, 0.0350),
+        ]
+    }
+
+    #[test]
+    fn test_sequential_bootstrap_reprices_helpers() {
+        let interpolations = [Interpolation::LinearZero, Interpolation::LogLinearDiscount, Interpolation::MonotoneConvex, Interpolation::CubicSpline];
+
+        for interpolation in interpolations {
+            let result = Bootstrapper::new(date(2024, 1, 1), Currency::USD).with_interpolation(interpolation).bootstrap(&create_helpers()).unwrap();
+
+            assert!(result.converged, "{:?} did not converge", interpolation);
+            for (helper, error) in create_helpers().iter().zip(result.reprice_errors.iter()) {
+                assert!(error.abs() < 1e-9, "{:?} misprices {:?} by {}", interpolation, helper, error);
+            }
+        }
+    }
+
+    #[test]
+    fn test_global_bootstrap_matches_sequential() {
+        let bootstrapper = Bootstrapper::new(date(2024, 1, 1), Currency::USD).with_interpolation(Interpolation::MonotoneConvex).with_tolerance(1e-10);
+        let sequential = bootstrapper.clone().bootstrap(&create_helpers()).unwrap();
+        let global = bootstrapper.with_method(BootstrapMethod::Global).bootstrap(&create_helpers()).unwrap();
+
+        assert!(global.converged);
+        for ((_, sequential_df), (_, global_df)) in sequential.curve.pillars().iter().zip(global.curve.pillars().iter()) {
+            assert!((sequential_df - global_df).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_single_deposit() {
+        let deposit = RateHelper::deposit(date(2024, 1, 1), date(2024, 7, 1), 0.04, DayCount::Actual360);
+        let result = Bootstrapper::new(date(2024, 1, 1), Currency::USD).bootstrap(&[deposit]).unwrap();
+
+        let expected = 1.0 / (1.0 + 0.04 * 182.0 / 360.0);
+        assert!((result.curve.discount_factor(date(2024, 7, 1)) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_helper_cashflows() {
+        let schedule = Schedule::new(date(2024, 1, 3), date(2026, 1, 3), Frequency::Annual, Calendar::Target);
+        let cashflows = RateHelper::swap(schedule, 0.03, DayCount::Thirty360Us).cashflows(Currency::EUR);
+
+        assert_eq!(cashflows.len(), 4);
+        assert_eq!(cashflows[0].amount, -1.0);
+        assert_eq!(cashflows[1].amount, 0.03);
+        assert_eq!(cashflows[3].amount, 1.0);
+        assert!(cashflows.iter().all(|cashflow| cashflow.currency == Currency::EUR));
+
+        let future = RateHelper::future(date(2024, 3, 20), date(2024, 6, 19), 95.0, 0.001, DayCount::Actual360);
+        assert!((future.cashflows(Currency::USD)[1].amount - (1.0 + 0.049 * 91.0 / 360.0)).abs() < 1e-15);
+
+        // The bootstrapped curve gives every helper's cash flows zero present value
+        let result = Bootstrapper::new(date(2024, 1, 1), Currency::EUR).bootstrap(&create_helpers()).unwrap();
+        for helper in create_helpers() {
+            let cashflows = helper.cashflows(Currency::EUR);
+            let value = cashflows.iter().fold(0.0, |value, cashflow| value + result.curve.present_value(cashflow).amount);
+            assert!(value.abs() < 1e-12, "{:?} has value {}", helper, value);
+            assert_eq!(result.curve.present_value(&cashflows[0]).currency, Currency::EUR);
+        }
+    }
+
+    #[test]
+    fn test_invalid_helpers() {
+        let bootstrapper = Bootstrapper::new(date(2024, 1, 1), Currency::USD);
+        assert!(matches!(bootstrapper.bootstrap(&[]), Err(QuantError::EmptyInput { .. })));
+
+        let duplicate = [
+            RateHelper::deposit(date(2024, 1, 1), date(2024, 7, 1), 0.04, DayCount::Actual360),
+            RateHelper::fra(date(2024, 4, 1), date(2024, 7, 1), 0.04, DayCount::Actual360),
+        ];
+        assert!(matches!(bootstrapper.bootstrap(&duplicate), Err(QuantError::InvalidInput { name: "pillar time", .. })));
+
+        // A swap whose schedule ends before it starts has no periods to place a pillar on
+        let inverted_schedule = Schedule::new(date(2026, 1, 3), date(2024, 1, 3), Frequency::Annual, Calendar::Target);
+        let inverted_swap = [RateHelper::swap(inverted_schedule, 0.03, DayCount::Thirty360Us)];
+        assert!(matches!(bootstrapper.bootstrap(&inverted_swap), Err(QuantError::InvalidInput { name: "accrual period", .. })));
+        let empty_deposit = [RateHelper::deposit(date(2024, 7, 1), date(2024, 7, 1), 0.04, DayCount::Actual360)];
+        assert!(matches!(bootstrapper.bootstrap(&empty_deposit), Err(QuantError::InvalidInput { name: "accrual period", .. })));
+    }
+
+    #[test]
+    fn test_reprice_errors_with_payment_lag() {
+        // Coupons paid two days after accrual end are what the helper cash flows discount in a single curve bootstrap
+        let swap = |years: i32, rate: f64| {
+            let schedule = Schedule::new(date(2024, 1, 3), date(2024 + years, 1, 3), Frequency::Annual, Calendar::Target).with_payment_lag(2);
+            RateHelper::index_swap(schedule, rate, DayCount::Actual360, RateIndex::Estr)
+        };
+        let helpers = [swap(1, 0.0350), swap(2, 0.0310), swap(5, 0.0280), swap(10, 0.0285)];
+        let result = Bootstrapper::new(date(2024, 1, 1), Currency::EUR).bootstrap(&helpers).unwrap();
+
+        assert!(result.converged);
+        assert!(result.reprice_errors.iter().all(|error| error.abs() < 1e-9), "Reprice errors {:?}", result.reprice_errors);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::cashflows::{CashFlow, Currency};
+use crate::curves::{DiscountCurve, RateIndex};
+use crate::error::QuantError;
+
+// Discount curves per currency, usually bootstrapped from overnight index swaps, and projection curves per floating
+// rate index. Overnight indices without their own projection curve are projected on the discount curve of their currency.
+#[derive(Default)]
+pub struct CurveSet<'a> {
+    discount_curves: HashMap<Currency, &'a dyn DiscountCurve>,
+    forward_curves: HashMap<RateIndex, &'a dyn DiscountCurve>,
+}
+
+impl<'a> CurveSet<'a> {
+    pub fn new() -> Self {
+        CurveSet::default()
+    }
+
+    pub fn with_discount_curve(mut self, currency: Currency, discount_curve: &'a dyn DiscountCurve) -> Self {
+        self.discount_curves.insert(currency, discount_curve);
+        self
+    }
+
+    pub fn with_forward_curve(mut self, index: RateIndex, forward_curve: &'a dyn DiscountCurve) -> Self {
+        self.forward_curves.insert(index, forward_curve);
+        self
+    }
+
+    pub fn discount_curve(&self, currency: Currency) -> Result<&'a dyn DiscountCurve, QuantError> {
+        self.discount_curves.get(&currency).copied().ok_or(QuantError::MissingDiscountCurve { currency })
+    }
+
+    pub fn forward_curve(&self, index: RateIndex) -> Result<&'a dyn DiscountCurve, QuantError> {
+        match self.forward_curves.get(&index) {
+            Some(forward_curve) => Ok(*forward_curve),
+            None if index.is_overnight() => self.discount_curve(index.currency()).map_err(|_| QuantError::MissingForwardCurve { index }),
+            None => Err(QuantError::MissingForwardCurve { index }),
+        }
+    }
+
+    // Simply compounded forward fixing of `index` over the accrual period, in the index day count
+    pub fn forward_rate(&self, index: RateIndex, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<f64, QuantError> {
+        let forward_curve = self.forward_curve(index)?;
+        let year_fraction = index.day_count().year_fraction(start, end);
+        Ok((forward_curve.discount_factor(start) / forward_curve.discount_factor(end) - 1.0) / year_fraction)
+    }
+
+    // Value at the reference date of the discount curve of the cash flow's currency
+    pub fn present_value(&self, cashflow: &CashFlow) -> Result<CashFlow, QuantError> {
+        Ok(self.discount_curve(cashflow.currency)?.present_value(cashflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::FlatDiscountCurve;
+    use crate::time::{date, DayCount};
+    use super::*;
+
+    #[test]
+    fn test_curve_lookup() {
+        let ois = FlatDiscountCurve::new(date(2024, 1, 1), 0.03, DayCount::Actual365Fixed);
+        let euribor = FlatDiscountCurve::new(date(2024, 1, 1), 0.035, DayCount::Actual365Fixed);
+        let curves = CurveSet::new().with_discount_curve(Currency::EUR, &ois).with_forward_curve(RateIndex::Euribor6M, &euribor);
+
+        let (start, end) = (date(2024, 7, 1), date(2025, 1, 1));
+        let estr = curves.forward_rate(RateIndex::Estr, start, end).unwrap();
+        let euribor_6m = curves.forward_rate(RateIndex::Euribor6M, start, end).unwrap();
+        assert!(euribor_6m > estr);
+        assert!((estr - ((0.03 * 184.0 / 365.0f64).exp() - 1.0) * 360.0 / 184.0).abs() < 1e-12);
+
+        assert_eq!(curves.forward_rate(RateIndex::Euribor3M, start, end), Err(QuantError::MissingForwardCurve { index: RateIndex::Euribor3M }));
+        assert_eq!(curves.forward_rate(RateIndex::Sofr, start, end), Err(QuantError::MissingForwardCurve { index: RateIndex::Sofr }));
+
+        let present_value = curves.present_value(&CashFlow::new(100.0, Currency::EUR, end)).unwrap();
+        assert!((present_value.amount - 100.0 * ois.discount_factor(end)).abs() < 1e-12);
+        assert!(matches!(curves.present_value(&CashFlow::new(100.0, Currency::USD, end)), Err(QuantError::MissingDiscountCurve { currency: Currency::USD })));
+    }
+}
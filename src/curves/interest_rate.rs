@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+
+use crate::time::DayCount;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Compounding {
+    Simple,
+    Periodic(u32), // Compounded this many times a year
+    Continuous,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InterestRate {
+    pub rate: f64,
+    pub day_count: DayCount,
+    pub compounding: Compounding,
+}
+
+impl InterestRate {
+    pub fn new(rate: f64, day_count: DayCount, compounding: Compounding) -> Self {
+        InterestRate { rate, day_count, compounding }
+    }
+
+    // Continuously compounded Act/365F rate, the convention of the option pricers
+    pub fn continuous(rate: f64) -> Self {
+        InterestRate::new(rate, DayCount::Actual365Fixed, Compounding::Continuous)
+    }
+
+    pub fn compound_factor_for(&self, year_fraction: f64) -> f64 {
+        match self.compounding {
+            Compounding::Simple => 1.0 + self.rate * year_fraction,
+            Compounding::Periodic(frequency) => (1.0 + self.rate / frequency as f64).powf(frequency as f64 * year_fraction),
+            Compounding::Continuous => (self.rate * year_fraction).exp(),
+        }
+    }
+
+    pub fn discount_factor_for(&self, year_fraction: f64) -> f64 {
+        1.0 / self.compound_factor_for(year_fraction)
+    }
+
+    pub fn compound_factor(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        self.compound_factor_for(self.day_count.year_fraction(start, end))
+    }
+
+    pub fn discount_factor(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        1.0 / self.compound_factor(start, end)
+    }
+
+    // Rate with the given conventions that grows by `compound_factor` over `year_fraction`. Over a vanishing period the
+    // instantaneous rates are matched instead.
+    pub fn implied_rate(compound_factor: f64, year_fraction: f64, day_count: DayCount, compounding: Compounding) -> InterestRate {
+        let rate = match compounding {
+            Compounding::Simple => (compound_factor - 1.0) / year_fraction,
+            Compounding::Periodic(frequency) => frequency as f64 * (compound_factor.powf(1.0 / (frequency as f64 * year_fraction)) - 1.0),
+            Compounding::Continuous => compound_factor.ln() / year_fraction,
+        };
+        InterestRate::new(rate, day_count, compounding)
+    }
+
+    fn instantaneous_rate(&self) -> f64 {
+        match self.compounding {
+            Compounding::Simple | Compounding::Continuous => self.rate,
+            Compounding::Periodic(frequency) => frequency as f64 * (self.rate / frequency as f64).ln_1p(),
+        }
+    }
+
+    // Rate in other conventions with the same compound factor between `start` and `end`
+    pub fn equivalent_rate(&self, start: DateTime<Utc>, end: DateTime<Utc>, day_count: DayCount, compounding: Compounding) -> InterestRate {
+        let year_fraction = day_count.year_fraction(start, end);
+        if year_fraction == 0.0 {
+            let instantaneous_rate = self.instantaneous_rate();
+            let rate = match compounding {
+                Compounding::Simple | Compounding::Continuous => instantaneous_rate,
+                Compounding::Periodic(frequency) => frequency as f64 * (instantaneous_rate / frequency as f64).exp_m1(),
+            };
+            return InterestRate::new(rate, day_count, compounding);
+        }
+
+        InterestRate::implied_rate(self.compound_factor(start, end), year_fraction, day_count, compounding)
+    }
+
+    // Continuously compounded Act/365F rate equivalent to this one up to `end`
+    pub fn continuous_rate_until(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        self.equivalent_rate(start, end, DayCount::Actual365Fixed, Compounding::Continuous).rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::date;
+    use super::*;
+
+    #[test]
+    fn test_compound_factors() {
+        let (start, end) = (date(2023, 1, 1), date(2024, 1, 1));
+
+        assert_eq!(InterestRate::new(0.05, DayCount::Actual365Fixed, Compounding::Simple).compound_factor(start, end), 1.05);
+        assert!((InterestRate::new(0.05, DayCount::Actual365Fixed, Compounding::Periodic(2)).compound_factor(start, end) - 1.025f64.powi(2)).abs() < 1e-15);
+        assert_eq!(InterestRate::continuous(0.05).discount_factor(start, end), 1.0 / 0.05f64.exp());
+        assert_eq!(InterestRate::new(0.036, DayCount::Actual360, Compounding::Simple).compound_factor(start, end), 1.0 + 0.036 * 365.0 / 360.0);
+    }
+
+    #[test]
+    fn test_equivalent_rates() {
+        let (start, end) = (date(2024, 1, 1), date(2026, 7, 1));
+        let annual = InterestRate::new(0.05, DayCount::ThirtyE360, Compounding::Periodic(1));
+
+        for compounding in [Compounding::Simple, Compounding::Periodic(4), Compounding::Continuous] {
+            let equivalent = annual.equivalent_rate(start, end, DayCount::Actual360, compounding);
+            assert!((equivalent.compound_factor(start, end) - annual.compound_factor(start, end)).abs() < 1e-12);
+        }
+
+        assert!((annual.continuous_rate_until(start, start) - 1.05f64.ln()).abs() < 1e-15);
+    }
+}
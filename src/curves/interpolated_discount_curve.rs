@@ -0,0 +1,314 @@
+use chrono::{DateTime, Utc};
+
+use crate::curves::DiscountCurve;
+use crate::error::QuantError;
+use crate::math::interpolation::{linear_interpolation, CubicSpline};
+use crate::time::DayCount;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    LinearZero, // Linear in continuously compounded zero rates
+    LogLinearDiscount, // Piecewise flat forwards
+    MonotoneConvex, // Hagan-West, continuous forwards that stay within the discrete forward bounds
+    CubicSpline, // Natural cubic spline on zero rates
+}
+
+// Beyond the last pillar
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Extrapolation {
+    FlatZero, // Hold the last zero rate
+    FlatForward, // Hold the forward rate of the last pillar period
+}
+
+// Discount curve interpolated between (date, discount factor) pillars, with time measured by `day_count` from the
+// reference date. Before the first pillar the curve starts from a unit discount factor at the reference date.
+#[derive(Clone, Debug)]
+pub struct InterpolatedDiscountCurve {
+    pub reference_datetime: DateTime<Utc>,
+    pub day_count: DayCount,
+    pub interpolation: Interpolation,
+    pub extrapolation: Extrapolation,
+    times: Vec<f64>,
+    discount_factors: Vec<f64>,
+    spline: Option<CubicSpline>,
+}
+
+// Hagan-West interpolant g on [0, 1] around the discrete forward and its integral G, for boundary values g0 and g1
+fn monotone_convex_g(g0: f64, g1: f64, x: f64) -> (f64, f64) {
+    if g0 == 0.0 && g1 == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    if (g0 < 0.0 && -0.5 * g0 <= g1 && g1 <= -2.0 * g0) || (g0 > 0.0 && -0.5 * g0 >= g1 && g1 >= -2.0 * g0) {
+        let g = g0 * (1.0 - 4.0 * x + 3.0 * x * x) + g1 * (-2.0 * x + 3.0 * x * x);
+        let integral = g0 * (x - 2.0 * x * x + x.powi(3)) + g1 * (-x * x + x.powi(3));
+        (g, integral)
+    } else if (g0 < 0.0 && g1 > -2.0 * g0) || (g0 > 0.0 && g1 < -2.0 * g0) {
+        let eta = (g1 + 2.0 * g0) / (g1 - g0);
+        if x <= eta {
+            (g0, g0 * x)
+        } else {
+            let scaled = (x - eta) / (1.0 - eta);
+            (g0 + (g1 - g0) * scaled * scaled, g0 * x + (g1 - g0) * (x - eta) * scaled * scaled / 3.0)
+        }
+    } else if (g0 > 0.0 && 0.0 > g1 && g1 > -0.5 * g0) || (g0 < 0.0 && 0.0 < g1 && g1 < -0.5 * g0) {
+        let eta = 3.0 * g1 / (g1 - g0);
+        if x < eta {
+            let scaled = (eta - x) / eta;
+            (g1 + (g0 - g1) * scaled * scaled, g1 * x + (g0 - g1) * eta / 3.0 * (1.0 - scaled.powi(3)))
+        } else {
+            (g1, g1 * x + (g0 - g1) * eta / 3.0)
+        }
+    } else {
+        let eta = g1 / (g1 + g0);
+        let a = -g0 * g1 / (g0 + g1);
+        if x <= eta {
+            let scaled = (eta - x) / eta;
+            (a + (g0 - a) * scaled * scaled, a * x + (g0 - a) * eta / 3.0 * (1.0 - scaled.powi(3)))
+        } else {
+            let scaled = (x - eta) / (1.0 - eta);
+            (a + (g1 - a) * scaled * scaled, a * x + (g0 - a) * eta / 3.0 + (g1 - a) * (x - eta) * scaled * scaled / 3.0)
+        }
+    }
+}
+
+impl InterpolatedDiscountCurve {
+    pub fn new(reference_datetime: DateTime<Utc>, pillars: Vec<(DateTime<Utc>, f64)>, day_count: DayCount, interpolation: Interpolation) -> Result<Self, QuantError> {
+        let times: Vec<f64> = pillars.iter().map(|(datetime, _)| day_count.year_fraction(reference_datetime, *datetime)).collect();
+        let discount_factors: Vec<f64> = pillars.iter().map(|(_, discount_factor)| *discount_factor).collect();
+
+        InterpolatedDiscountCurve::from_times(reference_datetime, times, discount_factors, day_count, interpolation)
+    }
+
+    pub fn from_times(reference_datetime: DateTime<Utc>, times: Vec<f64>, discount_factors: Vec<f64>, day_count: DayCount, interpolation: Interpolation) -> Result<Self, QuantError> {
+        if times.is_empty() {
+            return Err(QuantError::EmptyInput { name: "pillars" });
+        }
+        if discount_factors.len() != times.len() {
+            return Err(QuantError::LengthMismatch { name: "discount factors", expected: times.len(), actual: discount_factors.len() });
+        }
+        for (i, (&time, &discount_factor)) in times.iter().zip(discount_factors.iter()).enumerate() {
+            let previous = if i == 0 { 0.0 } else { times[i - 1] };
+            if time <= previous || !time.is_finite() {
+                return Err(QuantError::InvalidInput { name: "pillar time", value: time, reason: "pillars must be increasing and after the reference date" });
+            }
+            if discount_factor <= 0.0 || !discount_factor.is_finite() {
+                return Err(QuantError::InvalidInput { name: "discount factor", value: discount_factor, reason: "must be positive and finite" });
+            }
+        }
+
+        let spline = match interpolation {
+            Interpolation::CubicSpline => {
+                let zero_rates = times.iter().zip(discount_factors.iter()).map(|(t, df)| -df.ln() / t).collect();
+                Some(CubicSpline::new(times.clone(), zero_rates))
+            }
+            _ => None,
+        };
+
+        Ok(InterpolatedDiscountCurve { reference_datetime, day_count, interpolation, extrapolation: Extrapolation::FlatForward, times, discount_factors, spline })
+    }
+
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    pub fn pillars(&self) -> Vec<(f64, f64)> {
+        self.times.iter().copied().zip(self.discount_factors.iter().copied()).collect()
+    }
+
+    // -ln P(t), the integral of the instantaneous forward up to the pillar
+    fn log_discount(&self, i: usize) -> f64 {
+        -self.discount_factors[i].ln()
+    }
+
+    fn discrete_forward(&self, i: usize) -> f64 {
+        let (previous_time, previous_log_discount) = if i == 0 { (0.0, 0.0) } else { (self.times[i - 1], self.log_discount(i - 1)) };
+        (self.log_discount(i) - previous_log_discount) / (self.times[i] - previous_time)
+    }
+
+    fn monotone_convex_log_discount(&self, t: f64) -> f64 {
+        let n = self.times.len();
+        let forwards: Vec<f64> = (0..n).map(|i| self.discrete_forward(i)).collect();
+        let knots: Vec<f64> = std::iter::once(0.0).chain(self.times.iter().copied()).collect();
+
+        // Instantaneous forwards at the knots, with the end values chosen so that the neighbouring discrete forward is the average
+        let mut node_forwards = vec![0.0; n + 1];
+        for i in 1..n {
+            let (before, after) = (knots[i] - knots[i - 1], knots[i + 1] - knots[i]);
+            node_forwards[i] = (before * forwards[i] + after * forwards[i - 1]) / (before + after);
+        }
+        if n == 1 {
+            node_forwards[0] = forwards[0];
+            node_forwards[1] = forwards[0];
+        } else {
+            node_forwards[0] = forwards[0] - 0.5 * (node_forwards[1] - forwards[0]);
+            node_forwards[n] = forwards[n - 1] - 0.5 * (node_forwards[n - 1] - forwards[n - 1]);
+        }
+
+        let i = knots.partition_point(|&knot| knot < t).clamp(1, n);
+        let length = knots[i] - knots[i - 1];
+        let x = (t - knots[i - 1]) / length;
+        let (_, integral) = monotone_convex_g(node_forwards[i - 1] - forwards[i - 1], node_forwards[i] - forwards[i - 1], x);
+        let previous_log_discount = if i == 1 { 0.0 } else { self.log_discount(i - 2) };
+
+        previous_log_discount + forwards[i - 1] * (t - knots[i - 1]) + length * integral
+    }
+
+    // Discount factor `t` years after the reference date
+    pub fn discount_factor_at(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 1.0;
+        }
+
+        let last = self.times.len() - 1;
+        let last_time = self.times[last];
+        if t > last_time {
+            return match self.extrapolation {
+                Extrapolation::FlatZero => (-self.log_discount(last) / last_time * t).exp(),
+                Extrapolation::FlatForward => (-self.log_discount(last) - self.discrete_forward(last) * (t - last_time)).exp(),
+            };
+        }
+
+        let log_discount = match self.interpolation {
+            Interpolation::LinearZero => {
+                let zero_rates: Vec<f64> = (0..self.times.len()).map(|i| self.log_discount(i) / self.times[i]).collect();
+                let zero_rate = if t < self.times[0] { zero_rates[0] } else { linear_interpolation(&self.times, &zero_rates, t) };
+                zero_rate * t
+            }
+            Interpolation::LogLinearDiscount => {
+                let knots: Vec<f64> = std::iter::once(0.0).chain(self.times.iter().copied()).collect();
+                let log_discounts: Vec<f64> = std::iter::once(0.0).chain((0..self.times.len()).map(|i| self.log_discount(i))).collect();
+                linear_interpolation(&knots, &log_discounts, t)
+            }
+            Interpolation::MonotoneConvex => self.monotone_convex_log_discount(t),
+            Interpolation::CubicSpline => {
+                let spline = self.spline.as_ref().unwrap();
+                let zero_rate = if t < self.times[0] { spline.evaluate(self.times[0]) } else { spline.evaluate(t) };
+                zero_rate * t
+            }
+        };
+
+        (-log_discount).exp()
+    }
+
+    // Continuously compounded zero rate `t` years after the reference date
+    pub fn zero_rate_at(&self, t: f64) -> f64 {
+        let t = t.max(1e-6);
+        -self.discount_factor_at(t).ln() / t
+    }
+
+    // Instantaneous forward rate, by central differences of the log discount factor
+    pub fn instantaneous_forward_at(&self, t: f64) -> f64 {
+        let h = 1e-5;
+        let lower = (t - h).max(0.0);
+        (self.discount_factor_at(lower).ln() - self.discount_factor_at(t + h).ln()) / (t + h - lower)
+    }
+
+    pub fn instantaneous_forward(&self, datetime: DateTime<Utc>) -> f64 {
+        self.instantaneous_forward_at(self.day_count.year_fraction(self.reference_datetime, datetime))
+    }
+}
+
+impl DiscountCurve for InterpolatedDiscountCurve {
+    fn reference_datetime(&self) -> DateTime<Utc> {
+        self.reference_datetime
+    }
+
+    fn discount_factor(&self, datetime: DateTime<Utc>) -> f64 {
+        self.discount_factor_at(self.day_count.year_fraction(self.reference_datetime, datetime))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use crate::cashflows::{CashFlow, Currency};
+    use crate::curves::{Compounding, InterestRate};
+    use super::*;
+
+    const INTERPOLATIONS: [Interpolation; 4] = [Interpolation::LinearZero, Interpolation::LogLinearDiscount, Interpolation::MonotoneConvex, Interpolation::CubicSpline];
+
+    fn reference_datetime() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn create_curve(interpolation: Interpolation) -> InterpolatedDiscountCurve {
+        let times = vec![0.5, 1.0, 2.0, 5.0, 10.0];
+        let zero_rates = [0.030, 0.032, 0.035, 0.038, 0.040];
+        let discount_factors = times.iter().zip(zero_rates.iter()).map(|(t, r): (&f64, &f64)| (-r * t).exp()).collect();
+
+        InterpolatedDiscountCurve::from_times(reference_datetime(), times, discount_factors, DayCount::Actual365Fixed, interpolation).unwrap()
+    }
+
+    #[test]
+    fn test_pillars_are_repriced() {
+        for interpolation in INTERPOLATIONS {
+            let curve = create_curve(interpolation);
+            for (t, discount_factor) in curve.pillars() {
+                assert!((curve.discount_factor_at(t) - discount_factor).abs() < 1e-12, "{:?} misses pillar {}", interpolation, t);
+            }
+            assert_eq!(curve.discount_factor_at(0.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_interpolation_between_pillars() {
+        let linear_zero = create_curve(Interpolation::LinearZero);
+        assert!((linear_zero.zero_rate_at(1.5) - 0.0335).abs() < 1e-12);
+
+        let log_linear = create_curve(Interpolation::LogLinearDiscount);
+        assert!((log_linear.instantaneous_forward_at(3.0) - (5.0 * 0.038 - 2.0 * 0.035) / 3.0).abs() < 1e-8);
+
+        // Monotone convex forwards are continuous across pillars, unlike the flat forwards of log-linear discounting
+        let monotone_convex = create_curve(Interpolation::MonotoneConvex);
+        let jump = monotone_convex.instantaneous_forward_at(2.0 + 1e-3) - monotone_convex.instantaneous_forward_at(2.0 - 1e-3);
+        assert!(jump.abs() < 1e-3, "Forward jumps by {}", jump);
+
+        let spline = create_curve(Interpolation::CubicSpline);
+        assert!(spline.zero_rate_at(3.0) > 0.035 && spline.zero_rate_at(3.0) < 0.038);
+    }
+
+    #[test]
+    fn test_extrapolation() {
+        let flat_zero = create_curve(Interpolation::LinearZero).with_extrapolation(Extrapolation::FlatZero);
+        assert!((flat_zero.zero_rate_at(20.0) - 0.040).abs() < 1e-12);
+
+        let flat_forward = create_curve(Interpolation::LinearZero).with_extrapolation(Extrapolation::FlatForward);
+        let last_forward = (10.0 * 0.040 - 5.0 * 0.038) / 5.0;
+        assert!((flat_forward.instantaneous_forward_at(15.0) - last_forward).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_forward_rates_and_present_value() {
+        let curve = create_curve(Interpolation::LogLinearDiscount);
+        let (start, end) = (reference_datetime() + Duration::days(365), reference_datetime() + Duration::days(730));
+
+        let forward = curve.forward_rate(start, end, DayCount::Actual365Fixed, Compounding::Continuous);
+        assert!((forward.rate - (2.0 * 0.035 - 0.032)).abs() < 1e-12);
+        let simple_forward = curve.forward_rate(start, end, DayCount::Actual360, Compounding::Simple);
+        assert!((simple_forward.compound_factor(start, end) - curve.discount_factor(start) / curve.discount_factor(end)).abs() < 1e-12);
+
+        let zero = curve.zero_rate(end, DayCount::Actual365Fixed, Compounding::Continuous);
+        assert_eq!(zero, InterestRate::new(zero.rate, DayCount::Actual365Fixed, Compounding::Continuous));
+        assert!((zero.rate - 0.035).abs() < 1e-12);
+
+        let cashflow = CashFlow::new(100.0, Currency::USD, end);
+        let present_value = cashflow.value_with_curve(&curve);
+        assert!((present_value.amount - 100.0 * (-0.07f64).exp()).abs() < 1e-10);
+        assert_eq!(present_value.settlement_datetime, reference_datetime());
+    }
+
+    #[test]
+    fn test_invalid_pillars() {
+        let result = InterpolatedDiscountCurve::from_times(reference_datetime(), vec![1.0, 0.5], vec![0.97, 0.98], DayCount::Actual365Fixed, Interpolation::LinearZero);
+        assert!(matches!(result, Err(QuantError::InvalidInput { name: "pillar time", .. })));
+        assert!(InterpolatedDiscountCurve::new(reference_datetime(), vec![], DayCount::Actual365Fixed, Interpolation::LinearZero).is_err());
+
+        for interpolation in [Interpolation::LinearZero, Interpolation::CubicSpline] {
+            let result = InterpolatedDiscountCurve::from_times(reference_datetime(), vec![0.5, 1.0, 2.0], vec![0.98, 0.96], DayCount::Actual365Fixed, interpolation);
+            assert_eq!(result.err(), Some(QuantError::LengthMismatch { name: "discount factors", expected: 3, actual: 2 }));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+pub mod bootstrap;
+pub mod curve_set;
+pub mod interest_rate;
+pub mod interpolated_discount_curve;
+pub mod rate_index;
+pub use self::bootstrap::{BootstrapMethod, BootstrapResult, Bootstrapper, RateHelper};
+pub use self::curve_set::CurveSet;
+pub use self::interest_rate::{Compounding, InterestRate};
+pub use self::interpolated_discount_curve::{Extrapolation, InterpolatedDiscountCurve, Interpolation};
+pub use self::rate_index::RateIndex;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::cashflows::CashFlow;
+use crate::time::DayCount;
+
+pub trait DiscountCurve {
+    fn reference_datetime(&self) -> DateTime<Utc>;
+
+    fn discount_factor(&self, datetime: DateTime<Utc>) -> f64;
+
+    // Zero rate from the reference date, quoted with the given conventions. At the reference date itself the rate
+    // over the first day is returned.
+    fn zero_rate(&self, datetime: DateTime<Utc>, day_count: DayCount, compounding: Compounding) -> InterestRate {
+        let reference_datetime = self.reference_datetime();
+        let datetime = if datetime <= reference_datetime { reference_datetime + Duration::days(1) } else { datetime };
+        InterestRate::implied_rate(1.0 / self.discount_factor(datetime), day_count.year_fraction(reference_datetime, datetime), day_count, compounding)
+    }
+
+    fn forward_rate(&self, start: DateTime<Utc>, end: DateTime<Utc>, day_count: DayCount, compounding: Compounding) -> InterestRate {
+        let compound_factor = self.discount_factor(start) / self.discount_factor(end);
+        InterestRate::implied_rate(compound_factor, day_count.year_fraction(start, end), day_count, compounding)
+    }
+
+    // Value of the cash flow at the reference date
+    fn present_value(&self, cashflow: &CashFlow) -> CashFlow {
+        CashFlow::new(cashflow.amount * self.discount_factor(cashflow.settlement_datetime), cashflow.currency, self.reference_datetime())
+    }
+}
+
+// Single continuously compounded zero rate for every maturity
+#[derive(Clone, Debug)]
+pub struct FlatDiscountCurve {
+    pub reference_datetime: DateTime<Utc>,
+    pub rate: f64,
+    pub day_count: DayCount,
+}
+
+impl FlatDiscountCurve {
+    pub fn new(reference_datetime: DateTime<Utc>, rate: f64, day_count: DayCount) -> Self {
+        FlatDiscountCurve { reference_datetime, rate, day_count }
+    }
+}
+
+impl DiscountCurve for FlatDiscountCurve {
+    fn reference_datetime(&self) -> DateTime<Utc> {
+        self.reference_datetime
+    }
+
+    fn discount_factor(&self, datetime: DateTime<Utc>) -> f64 {
+        (-self.rate * self.day_count.year_fraction(self.reference_datetime, datetime)).exp()
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+use crate::cashflows::Currency;
+use crate::time::{Calendar, DayCount, Frequency};
+
+// Floating rate benchmark, identifying a projection curve within a `CurveSet`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RateIndex {
+    Sofr,
+    Estr,
+    Sonia,
+    Euribor3M,
+    Euribor6M,
+}
+
+impl RateIndex {
+    pub fn currency(&self) -> Currency {
+        match self {
+            RateIndex::Sofr => Currency::USD,
+            RateIndex::Estr | RateIndex::Euribor3M | RateIndex::Euribor6M => Currency::EUR,
+            RateIndex::Sonia => Currency::GBP,
+        }
+    }
+
+    pub fn day_count(&self) -> DayCount {
+        match self {
+            RateIndex::Sonia => DayCount::Actual365Fixed,
+            _ => DayCount::Actual360,
+        }
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        match self {
+            RateIndex::Sofr => Calendar::UsGovernmentBond,
+            RateIndex::Sonia => Calendar::London,
+            RateIndex::Estr | RateIndex::Euribor3M | RateIndex::Euribor6M => Calendar::Target,
+        }
+    }
+
+    // Accrual period of a term rate fixing, None for overnight rates compounded over the coupon period
+    pub fn tenor(&self) -> Option<Frequency> {
+        match self {
+            RateIndex::Sofr | RateIndex::Estr | RateIndex::Sonia => None,
+            RateIndex::Euribor3M => Some(Frequency::Quarterly),
+            RateIndex::Euribor6M => Some(Frequency::SemiAnnual),
+        }
+    }
+
+    pub fn is_overnight(&self) -> bool {
+        self.tenor().is_none()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RateIndex::Sofr => "SOFR",
+            RateIndex::Estr => "ESTR",
+            RateIndex::Sonia => "SONIA",
+            RateIndex::Euribor3M => "EURIBOR 3M",
+            RateIndex::Euribor6M => "EURIBOR 6M",
+        }
+    }
+}
+
+impl fmt::Display for RateIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,79 @@
+use std::error::Error;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use crate::cashflows::Currency;
+use crate::curves::RateIndex;
+use crate::processes::process_error::{check_finite, check_positive, ProcessError};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuantError {
+    CurrencyMismatch { left: Currency, right: Currency },
+    SettlementMismatch { left: DateTime<Utc>, right: DateTime<Utc> },
+    DivisionByZero,
+    EmptyInput { name: &'static str },
+    LengthMismatch { name: &'static str, expected: usize, actual: usize },
+    ExpiredInstrument { exercise_datetime: DateTime<Utc> },
+    MissingFxRate { from: Currency, to: Currency },
+    MissingDiscountCurve { currency: Currency },
+    MissingForwardCurve { index: RateIndex },
+    UnknownCurrency { code: String },
+    InvalidCurrencyCode { code: String },
+    DuplicateCurrency { code: String },
+    InvalidInput { name: &'static str, value: f64, reason: &'static str },
+    InvalidDateRange { start: DateTime<Utc>, end: DateTime<Utc> },
+    RootNotFound { name: &'static str },
+    Process(ProcessError),
+}
+
+impl fmt::Display for QuantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuantError::CurrencyMismatch { left, right } => write!(f, "Cannot operate on cashflows with different currencies. Got {} and {}.", left, right),
+            QuantError::SettlementMismatch { left, right } => write!(f, "Cannot operate on cashflows with different settlement dates. Got {} and {}.", left, right),
+            QuantError::DivisionByZero => write!(f, "Attempt to divide by zero."),
+            QuantError::EmptyInput { name } => write!(f, "Input {} must not be empty.", name),
+            QuantError::LengthMismatch { name, expected, actual } => write!(f, "Input {} must have {} elements, got {}.", name, expected, actual),
+            QuantError::ExpiredInstrument { exercise_datetime } => write!(f, "Instrument expired on {}.", exercise_datetime),
+            QuantError::MissingFxRate { from, to } => write!(f, "No FX rate available to convert {} into {}.", from, to),
+            QuantError::MissingDiscountCurve { currency } => write!(f, "No discount curve available for {}.", currency),
+            QuantError::MissingForwardCurve { index } => write!(f, "No forward curve available for {}.", index),
+            QuantError::UnknownCurrency { code } => write!(f, "Unknown currency code {}.", code),
+            QuantError::InvalidCurrencyCode { code } => write!(f, "Invalid currency code {:?}: codes must be non-empty and alphanumeric.", code),
+            QuantError::DuplicateCurrency { code } => write!(f, "Currency {} is already defined.", code),
+            QuantError::InvalidInput { name, value, reason } => write!(f, "Invalid input {} = {}: {}.", name, value, reason),
+            QuantError::InvalidDateRange { start, end } => write!(f, "Start date {} must be before end date {}.", start, end),
+            QuantError::RootNotFound { name } => write!(f, "No {} solves the equation within the search range.", name),
+            QuantError::Process(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for QuantError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            QuantError::Process(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProcessError> for QuantError {
+    fn from(error: ProcessError) -> Self {
+        QuantError::Process(error)
+    }
+}
+
+// Pricer inputs share the process parameter checks but report them as invalid inputs
+pub(crate) fn check_positive_input(name: &'static str, value: f64) -> Result<(), QuantError> {
+    check_positive(name, value).map_err(|_| QuantError::InvalidInput { name, value, reason: "must be positive and finite" })
+}
+
+pub(crate) fn check_finite_input(name: &'static str, value: f64) -> Result<(), QuantError> {
+    check_finite(name, value).map_err(|_| QuantError::InvalidInput { name, value, reason: "must be finite" })
+}
+
+pub(crate) fn check_count(name: &'static str, count: usize) -> Result<(), QuantError> {
+    if count > 0 { Ok(()) } else { Err(QuantError::EmptyInput { name }) }
+}
@@ -1,7 +1,9 @@
 use chrono::{DateTime, Utc};
 use crate::cashflows::{CashFlow, Currency};
 use crate::instruments::{OptionType, Value};
+use crate::time::Calendar;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BarrierOption {
     pub strike: f64,
     pub exercise_datetime: DateTime<Utc>,
@@ -11,12 +13,14 @@ pub struct BarrierOption {
     pub underlying_currency: Currency,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Barrier{
     pub barrier_type: BarrierType,
     pub level: f64,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BarrierType {
     UpAndIn,
     UpAndOut,
@@ -24,8 +28,19 @@ pub enum BarrierType {
     DownAndOut
 }
 
+impl BarrierOption {
+    pub fn new(strike: f64, exercise_datetime: DateTime<Utc>, settlement_datetime: DateTime<Utc>, option_type: OptionType, barrier: Barrier, underlying_currency: Currency) -> Self {
+        BarrierOption { strike, exercise_datetime, settlement_datetime, option_type, barrier, underlying_currency }
+    }
+
+    // Settles `spot_lag` business days after exercise on `calendar`, e.g. T+2
+    pub fn with_spot_lag(strike: f64, exercise_datetime: DateTime<Utc>, spot_lag: u32, calendar: &Calendar, option_type: OptionType, barrier: Barrier, underlying_currency: Currency) -> Self {
+        BarrierOption::new(strike, exercise_datetime, calendar.spot_date(exercise_datetime, spot_lag), option_type, barrier, underlying_currency)
+    }
+}
+
 impl Value for BarrierOption {
-    fn calculate_payoff(&self, price_path: &Vec<f64>) -> CashFlow {
+    fn calculate_payoff(&self, price_path: &[f64]) -> CashFlow {
         let barrier_crossed = match self.barrier.barrier_type {
             BarrierType::UpAndIn | BarrierType::UpAndOut => price_path.iter().any(|&p| p >= self.barrier.level),
             BarrierType::DownAndIn | BarrierType::DownAndOut => price_path.iter().any(|&p| p <= self.barrier.level),
@@ -53,3 +68,32 @@ impl Value for BarrierOption {
     fn underlying_currency(&self) -> Currency { self.underlying_currency }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate::time::date;
+    use super::*;
+
+    #[test]
+    fn test_spot_lag_settlement() {
+        let barrier = Barrier { barrier_type: BarrierType::DownAndOut, level: 80.0 };
+        let option = BarrierOption::with_spot_lag(100.0, date(2024, 3, 28), 2, &Calendar::Target, OptionType::Put, barrier, Currency::EUR);
+
+        // Good Friday and Easter Monday are TARGET holidays
+        assert_eq!(option.settlement_datetime, date(2024, 4, 3));
+        assert_eq!(option.calculate_payoff(&[95.0, 90.0]).settlement_datetime, date(2024, 4, 3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let expiry = date(2025, 6, 20);
+        let barrier = Barrier { barrier_type: BarrierType::UpAndOut, level: 130.0 };
+        let option = BarrierOption::new(100.0, expiry, expiry, OptionType::Call, barrier, Currency::EUR);
+        let json = serde_json::to_string(&option).unwrap();
+
+        assert!(json.contains(r#""barrier":{"barrier_type":"UpAndOut","level":130.0}"#));
+        let parsed: BarrierOption = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.barrier.barrier_type, BarrierType::UpAndOut);
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+}
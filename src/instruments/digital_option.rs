@@ -0,0 +1,186 @@
+use chrono::{DateTime, Utc};
+
+use crate::cashflows::{CashFlow, Currency};
+use crate::error::{check_finite_input, check_positive_input, QuantError};
+use crate::instruments::vanilla_option::VanillaOption;
+use crate::instruments::{OptionType, Value};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DigitalPayoff {
+    CashOrNothing(f64), // Pays this fixed amount when in the money
+    AssetOrNothing, // Pays the underlying price when in the money
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct DigitalOption {
+    pub strike: f64,
+    pub exercise_datetime: DateTime<Utc>,
+    pub settlement_datetime: DateTime<Utc>,
+    pub option_type: OptionType,
+    pub payoff: DigitalPayoff,
+    pub underlying_currency: Currency,
+    pub call_spread_width: Option<f64>, // Simulated payoffs follow the call spread replication of this strike width
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(DigitalOption, crate::instruments::Value);
+
+impl DigitalOption {
+    pub fn new(strike: f64, exercise_datetime: DateTime<Utc>, settlement_datetime: DateTime<Utc>, option_type: OptionType, payoff: DigitalPayoff, underlying_currency: Currency) -> Self {
+        DigitalOption { strike, exercise_datetime, settlement_datetime, option_type, payoff, underlying_currency, call_spread_width: None }
+    }
+
+    // Replaces the jump at the strike by a linear ramp over `width`, which removes the discontinuity that makes Monte
+    // Carlo greeks noisy and PDE prices oscillate. The width must be positive.
+    pub fn with_call_spread(mut self, width: f64) -> Self {
+        self.call_spread_width = Some(width);
+        self
+    }
+
+    // Weighted vanilla options whose combined payoff is the digital payoff with the jump spread evenly over
+    // [strike - width / 2, strike + width / 2]. Asset-or-nothing digitals add a vanilla struck at the strike.
+    pub fn call_spread_replication(&self, width: f64) -> Vec<(f64, VanillaOption)> {
+        let vanilla = |strike: f64| VanillaOption::new(strike, self.exercise_datetime, self.settlement_datetime, self.option_type, self.underlying_currency);
+        let (lower, upper) = (self.strike - 0.5 * width, self.strike + 0.5 * width);
+
+        let (cash, asset_leg) = match self.payoff {
+            DigitalPayoff::CashOrNothing(cash) => (cash, None),
+            DigitalPayoff::AssetOrNothing => (self.strike, Some(match self.option_type {
+                OptionType::Call => (1.0, vanilla(self.strike)),
+                OptionType::Put => (-1.0, vanilla(self.strike)),
+            })),
+        };
+
+        let mut replication = match self.option_type {
+            OptionType::Call => vec![(cash / width, vanilla(lower)), (-cash / width, vanilla(upper))],
+            OptionType::Put => vec![(cash / width, vanilla(upper)), (-cash / width, vanilla(lower))],
+        };
+        replication.extend(asset_leg);
+        replication
+    }
+
+    fn is_in_the_money(&self, price: f64) -> bool {
+        match self.option_type {
+            OptionType::Call => price > self.strike,
+            OptionType::Put => price < self.strike,
+        }
+    }
+}
+
+impl Value for DigitalOption {
+    fn calculate_payoff(&self, price_path: &[f64]) -> CashFlow {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+        let price = *price_path.last().unwrap();
+
+        let amount = match self.call_spread_width {
+            Some(width) => self.call_spread_replication(width).iter()
+                .map(|(weight, option)| weight * option.calculate_payoff(price_path).amount)
+                .sum(),
+            None if self.is_in_the_money(price) => match self.payoff {
+                DigitalPayoff::CashOrNothing(cash) => cash,
+                DigitalPayoff::AssetOrNothing => price,
+            },
+            None => 0.0,
+        };
+
+        CashFlow::new(amount, self.underlying_currency, self.settlement_datetime)
+    }
+
+    fn validate(&self) -> Result<(), QuantError> {
+        if let DigitalPayoff::CashOrNothing(cash) = self.payoff {
+            check_finite_input("cash", cash)?;
+        }
+        match self.call_spread_width {
+            Some(width) => check_positive_input("call_spread_width", width),
+            None => Ok(()),
+        }
+    }
+
+    fn settlement_datetime(&self) -> DateTime<Utc> {
+        self.settlement_datetime
+    }
+
+    fn underlying_currency(&self) -> Currency {
+        self.underlying_currency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use crate::processes::simulated_path::SimulatedPath;
+    use super::*;
+
+    fn create_option(option_type: OptionType, payoff: DigitalPayoff) -> DigitalOption {
+        let expiry = Utc.with_ymd_and_hms(2030, 6, 20, 0, 0, 0).unwrap();
+        DigitalOption::new(100.0, expiry, expiry, option_type, payoff, Currency::USD)
+    }
+
+    #[test]
+    fn test_payoffs() {
+        let cash_call = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(10.0));
+        assert_eq!(cash_call.calculate_payoff(&[90.0, 101.0]).amount, 10.0);
+        assert_eq!(cash_call.calculate_payoff(&[110.0, 99.0]).amount, 0.0);
+
+        let asset_put = create_option(OptionType::Put, DigitalPayoff::AssetOrNothing);
+        assert_eq!(asset_put.calculate_payoff(&[95.0]).amount, 95.0);
+        assert_eq!(asset_put.calculate_payoff(&[105.0]).amount, 0.0);
+    }
+
+    #[test]
+    fn test_call_spread_smoothing() {
+        let smoothed_call = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(10.0)).with_call_spread(2.0);
+        assert_eq!(smoothed_call.calculate_payoff(&[98.0]).amount, 0.0);
+        assert!((smoothed_call.calculate_payoff(&[100.5]).amount - 7.5).abs() < 1e-12);
+        assert!((smoothed_call.calculate_payoff(&[102.0]).amount - 10.0).abs() < 1e-12);
+
+        let smoothed_put = create_option(OptionType::Put, DigitalPayoff::CashOrNothing(1.0)).with_call_spread(2.0);
+        assert!((smoothed_put.calculate_payoff(&[99.5]).amount - 0.75).abs() < 1e-12);
+
+        // Away from the strike the replication matches the exact asset-or-nothing payoff
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let exact = create_option(option_type, DigitalPayoff::AssetOrNothing);
+            let smoothed = exact.clone().with_call_spread(1.0);
+            for price in [80.0, 99.0, 101.0, 120.0] {
+                assert!((smoothed.calculate_payoff(&[price]).amount - exact.calculate_payoff(&[price]).amount).abs() < 1e-12, "{:?} at {}", option_type, price);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_call_spread_width() {
+        let option = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(10.0));
+        assert_eq!(option.validate(), Ok(()));
+        assert!(matches!(option.clone().with_call_spread(0.0).validate(), Err(QuantError::InvalidInput { name: "call_spread_width", .. })));
+        assert!(option.clone().with_call_spread(-2.0).try_calculate_path_payoff(&SimulatedPath::from_spot(vec![101.0])).is_err());
+
+        let nan_cash = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(f64::NAN));
+        assert!(matches!(nan_cash.validate(), Err(QuantError::InvalidInput { name: "cash", .. })));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid input call_spread_width = 0: must be positive and finite.")]
+    fn test_payoff_refuses_zero_call_spread_width() {
+        let option = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(10.0)).with_call_spread(0.0);
+        let _ = option.calculate_payoff(&[100.0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let option = create_option(OptionType::Put, DigitalPayoff::CashOrNothing(10.0)).with_call_spread(2.0);
+        let json = serde_json::to_string(&option).unwrap();
+
+        assert!(json.contains(r#""payoff":{"CashOrNothing":10.0}"#));
+        let parsed: DigitalOption = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.call_spread_width, Some(2.0));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+
+        // Deserialization validates the option like `Value::validate`
+        let error = serde_json::from_str::<DigitalOption>(&json.replace(r#""call_spread_width":2.0"#, r#""call_spread_width":0.0"#)).unwrap_err();
+        assert!(error.to_string().contains("call_spread_width"));
+    }
+}
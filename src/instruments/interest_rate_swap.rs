@@ -0,0 +1,141 @@
+use crate::cashflows::{fixed_coupon_cashflows, CashFlow, Currency};
+use crate::curves::{CurveSet, RateIndex};
+use crate::error::QuantError;
+use crate::time::{DayCount, Schedule};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SwapDirection {
+    Payer, // Pays fixed, receives floating
+    Receiver,
+}
+
+// Fixed against floating swap in the currency of its index, priced off a `CurveSet` with forwards projected on the
+// index curve and every cash flow discounted on the currency's discount curve
+#[derive(Clone, Debug)]
+pub struct InterestRateSwap {
+    pub notional: f64,
+    pub direction: SwapDirection,
+    pub fixed_schedule: Schedule,
+    pub fixed_rate: f64,
+    pub fixed_day_count: DayCount,
+    pub floating_schedule: Schedule,
+    pub index: RateIndex,
+    pub spread: f64, // Added to every floating fixing
+}
+
+impl InterestRateSwap {
+    pub fn new(notional: f64, direction: SwapDirection, fixed_schedule: Schedule, fixed_rate: f64, fixed_day_count: DayCount, floating_schedule: Schedule, index: RateIndex) -> Self {
+        InterestRateSwap { notional, direction, fixed_schedule, fixed_rate, fixed_day_count, floating_schedule, index, spread: 0.0 }
+    }
+
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.index.currency()
+    }
+
+    pub fn fixed_leg_cashflows(&self) -> Vec<CashFlow> {
+        fixed_coupon_cashflows(&self.fixed_schedule, self.notional, self.fixed_rate, self.fixed_day_count, self.currency())
+    }
+
+    pub fn floating_leg_cashflows(&self, curves: &CurveSet) -> Result<Vec<CashFlow>, QuantError> {
+        self.floating_schedule.periods().iter()
+            .map(|period| {
+                let fixing = curves.forward_rate(self.index, period.accrual_start, period.accrual_end)?;
+                let year_fraction = self.index.day_count().year_fraction(period.accrual_start, period.accrual_end);
+                Ok(CashFlow::new(self.notional * (fixing + self.spread) * year_fraction, self.currency(), period.payment_datetime))
+            })
+            .collect()
+    }
+
+    fn leg_value(cashflows: &[CashFlow], curves: &CurveSet) -> Result<f64, QuantError> {
+        cashflows.iter().map(|cashflow| Ok(curves.present_value(cashflow)?.amount)).sum()
+    }
+
+    // Value of one basis point on the fixed leg
+    pub fn annuity(&self, curves: &CurveSet) -> Result<f64, QuantError> {
+        let unit_coupons = fixed_coupon_cashflows(&self.fixed_schedule, self.notional, 1e-4, self.fixed_day_count, self.currency());
+        InterestRateSwap::leg_value(&unit_coupons, curves)
+    }
+
+    // Value at the reference date of the discount curve, from the point of view of `direction`
+    pub fn npv(&self, curves: &CurveSet) -> Result<CashFlow, QuantError> {
+        let fixed_leg = InterestRateSwap::leg_value(&self.fixed_leg_cashflows(), curves)?;
+        let floating_leg = InterestRateSwap::leg_value(&self.floating_leg_cashflows(curves)?, curves)?;
+        let value = match self.direction {
+            SwapDirection::Payer => floating_leg - fixed_leg,
+            SwapDirection::Receiver => fixed_leg - floating_leg,
+        };
+
+        Ok(CashFlow::new(value, self.currency(), curves.discount_curve(self.currency())?.reference_datetime()))
+    }
+
+    // Fixed rate that gives the swap zero value
+    pub fn fair_rate(&self, curves: &CurveSet) -> Result<f64, QuantError> {
+        let floating_leg = InterestRateSwap::leg_value(&self.floating_leg_cashflows(curves)?, curves)?;
+        Ok(floating_leg / self.annuity(curves)? * 1e-4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::{Bootstrapper, DiscountCurve, RateHelper};
+    use crate::time::{date, Calendar, Frequency};
+    use super::*;
+
+    fn annual_schedule(years: i32) -> Schedule {
+        Schedule::new(date(2024, 1, 3), date(2024 + years, 1, 3), Frequency::Annual, Calendar::Target)
+    }
+
+    #[test]
+    fn test_dual_curve_bootstrap_reprices_swaps() {
+        let reference_datetime = date(2024, 1, 1);
+        let estr_swaps: Vec<RateHelper> = [(1, 0.0350), (2, 0.0310), (5, 0.0280), (10, 0.0285)].iter()
+            .map(|&(years, rate)| RateHelper::index_swap(annual_schedule(years), rate, DayCount::Actual360, RateIndex::Estr))
+            .collect();
+        let ois = Bootstrapper::new(reference_datetime, Currency::EUR).bootstrap(&estr_swaps).unwrap();
+
+        let mut euribor_helpers = vec![RateHelper::deposit(date(2024, 1, 3), date(2024, 7, 3), 0.0390, DayCount::Actual360)];
+        euribor_helpers.extend([(2, 0.0340), (5, 0.0310), (10, 0.0315)].iter()
+            .map(|&(years, rate)| RateHelper::index_swap(annual_schedule(years), rate, DayCount::Thirty360Us, RateIndex::Euribor6M)));
+        let euribor = Bootstrapper::new(reference_datetime, Currency::EUR).bootstrap_forward_curve(&euribor_helpers, &ois.curve).unwrap();
+
+        assert!(euribor.converged);
+        assert!(euribor.reprice_errors.iter().all(|error| error.abs() < 1e-9));
+
+        let curves = CurveSet::new().with_discount_curve(Currency::EUR, &ois.curve).with_forward_curve(RateIndex::Euribor6M, &euribor.curve);
+        let floating_schedule = Schedule::new(date(2024, 1, 3), date(2029, 1, 3), Frequency::SemiAnnual, Calendar::Target);
+        let swap = InterestRateSwap::new(10_000_000.0, SwapDirection::Payer, annual_schedule(5), 0.0310, DayCount::Thirty360Us, floating_schedule, RateIndex::Euribor6M);
+
+        assert!(swap.npv(&curves).unwrap().amount.abs() < 1e-4);
+        assert!((swap.fair_rate(&curves).unwrap() - 0.0310).abs() < 1e-12);
+        assert_eq!(swap.npv(&curves).unwrap().settlement_datetime, reference_datetime);
+
+        // Projecting EURIBOR off the OIS curve, as a single curve setup would, understates the floating leg
+        let single_curve = CurveSet::new().with_discount_curve(Currency::EUR, &ois.curve).with_forward_curve(RateIndex::Euribor6M, &ois.curve);
+        assert!(swap.npv(&single_curve).unwrap().amount < -10_000.0);
+        assert!(euribor.curve.discount_factor(date(2029, 1, 3)) < ois.curve.discount_factor(date(2029, 1, 3)));
+    }
+
+    #[test]
+    fn test_swap_legs_and_direction() {
+        let ois = crate::curves::FlatDiscountCurve::new(date(2024, 1, 1), 0.03, DayCount::Actual365Fixed);
+        let curves = CurveSet::new().with_discount_curve(Currency::USD, &ois).with_discount_curve(Currency::EUR, &ois);
+        let payer = InterestRateSwap::new(1_000_000.0, SwapDirection::Payer, annual_schedule(3), 0.04, DayCount::Actual360, annual_schedule(3), RateIndex::Sofr);
+        let receiver = InterestRateSwap { direction: SwapDirection::Receiver, ..payer.clone() };
+
+        assert_eq!(payer.fixed_leg_cashflows().len(), 3);
+        assert!(payer.fixed_leg_cashflows().iter().all(|cashflow| cashflow.currency == Currency::USD));
+        assert_eq!(payer.npv(&curves).unwrap().amount, -receiver.npv(&curves).unwrap().amount);
+        assert!(payer.npv(&curves).unwrap().amount < 0.0);
+
+        let with_spread = payer.clone().with_spread(0.04 - payer.fair_rate(&curves).unwrap());
+        assert!(with_spread.npv(&curves).unwrap().amount.abs() < 1e-6 * with_spread.notional);
+
+        let euribor_swap = InterestRateSwap { index: RateIndex::Euribor3M, ..payer };
+        assert!(matches!(euribor_swap.npv(&curves), Err(QuantError::MissingForwardCurve { index: RateIndex::Euribor3M })));
+    }
+}
@@ -2,17 +2,48 @@ use chrono::{DateTime, Utc};
 
 use crate::cashflows::cashflow::CashFlow;
 use crate::cashflows::currency::Currency;
+use crate::error::QuantError;
+use crate::processes::simulated_path::SimulatedPath;
 
 pub mod vanilla_option;
 pub mod barrier_option;
+pub mod digital_option;
+pub mod interest_rate_swap;
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OptionType {
     Call,
     Put,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExerciseStyle {
+    European,
+    American,
+}
+
 pub trait Value {
-    fn calculate_payoff(&self, price_path: &Vec<f64>) -> CashFlow;
+    fn calculate_payoff(&self, price_path: &[f64]) -> CashFlow;
+
+    // Payoffs depending on state variables other than the spot override this
+    fn calculate_path_payoff(&self, path: &SimulatedPath) -> CashFlow {
+        self.calculate_payoff(path.spot())
+    }
+
+    // Instruments with terms that can be invalid, e.g. a non-positive smoothing width, override this
+    fn validate(&self) -> Result<(), QuantError> {
+        Ok(())
+    }
+
+    fn try_calculate_path_payoff(&self, path: &SimulatedPath) -> Result<CashFlow, QuantError> {
+        self.validate()?;
+        if path.spot().is_empty() {
+            return Err(QuantError::EmptyInput { name: "spot path" });
+        }
+        Ok(self.calculate_path_payoff(path))
+    }
 
     fn settlement_datetime(&self) -> DateTime<Utc>;
     
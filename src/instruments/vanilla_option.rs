@@ -5,7 +5,9 @@ use chrono::{DateTime, Utc};
 use crate::cashflows::CashFlow;
 use crate::cashflows::Currency;
 use crate::instruments::{OptionType, Value};
+use crate::time::Calendar;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VanillaOption {
     pub strike: f64,
     pub exercise_datetime: DateTime<Utc>,
@@ -24,11 +26,16 @@ impl VanillaOption {
             underlying_currency,
         }
     }
+
+    // Settles `spot_lag` business days after exercise on `calendar`, e.g. T+2
+    pub fn with_spot_lag(strike: f64, exercise_datetime: DateTime<Utc>, spot_lag: u32, calendar: &Calendar, option_type: OptionType, underlying_currency: Currency) -> Self {
+        VanillaOption::new(strike, exercise_datetime, calendar.spot_date(exercise_datetime, spot_lag), option_type, underlying_currency)
+    }
 }
 
 impl Value for VanillaOption
 {
-    fn calculate_payoff(&self, price_path: &Vec<f64>) -> CashFlow {
+    fn calculate_payoff(&self, price_path: &[f64]) -> CashFlow {
         match self.option_type {
             OptionType::Call => CashFlow::new((price_path.last().unwrap() - self.strike).max(0.0), self.underlying_currency, self.settlement_datetime),
             OptionType::Put => CashFlow::new((self.strike - price_path.last().unwrap()).max(0.0), self.underlying_currency, self.settlement_datetime)
@@ -41,3 +48,20 @@ impl Value for VanillaOption
 
     fn underlying_currency(&self) -> Currency { self.underlying_currency }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_serde_round_trip() {
+        let exercise_datetime = Utc.with_ymd_and_hms(2025, 6, 20, 0, 0, 0).unwrap();
+        let option = VanillaOption::with_spot_lag(105.0, exercise_datetime, 2, &Calendar::Nyse, OptionType::Put, Currency::USD);
+        let json = serde_json::to_string(&option).unwrap();
+
+        assert_eq!(json, r#"{"strike":105.0,"exercise_datetime":"2025-06-20T00:00:00Z","settlement_datetime":"2025-06-24T00:00:00Z","option_type":"Put","underlying_currency":"USD"}"#);
+        assert_eq!(serde_json::to_string(&serde_json::from_str::<VanillaOption>(&json).unwrap()).unwrap(), json);
+    }
+}
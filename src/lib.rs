@@ -2,4 +2,9 @@ pub mod processes;
 pub mod instruments;
 pub mod pricing;
 pub mod cashflows;
+pub mod math;
+pub mod calibration;
+pub mod time;
+pub mod error;
+pub mod curves;
 
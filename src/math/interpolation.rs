@@ -0,0 +1,89 @@
+// Index of the segment [x[i], x[i + 1]] containing `t`, clamped to the first and last segments
+fn segment(x: &[f64], t: f64) -> usize {
+    x.partition_point(|&knot| knot <= t).clamp(1, x.len() - 1) - 1
+}
+
+pub fn linear_interpolation(x: &[f64], y: &[f64], t: f64) -> f64 {
+    if x.len() == 1 {
+        return y[0];
+    }
+    let i = segment(x, t);
+    y[i] + (y[i + 1] - y[i]) * (t - x[i]) / (x[i + 1] - x[i])
+}
+
+// Natural cubic spline through (x, y), with zero second derivative at both ends
+#[derive(Clone, Debug)]
+pub struct CubicSpline {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    second_derivatives: Vec<f64>,
+}
+
+impl CubicSpline {
+    pub fn new(x: Vec<f64>, y: Vec<f64>) -> Self {
+        let n = x.len();
+        let mut second_derivatives = vec![0.0; n];
+
+        if n > 2 {
+            // Thomas algorithm on the tridiagonal system for the interior second derivatives
+            let mut diagonal = vec![0.0; n];
+            let mut rhs = vec![0.0; n];
+            for i in 1..n - 1 {
+                let (h0, h1) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+                diagonal[i] = 2.0 * (h0 + h1);
+                rhs[i] = 6.0 * ((y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0);
+                if i > 1 {
+                    let factor = h0 / diagonal[i - 1];
+                    diagonal[i] -= factor * h0;
+                    rhs[i] -= factor * rhs[i - 1];
+                }
+            }
+            for i in (1..n - 1).rev() {
+                let h1 = x[i + 1] - x[i];
+                second_derivatives[i] = (rhs[i] - h1 * second_derivatives[i + 1]) / diagonal[i];
+            }
+        }
+
+        CubicSpline { x, y, second_derivatives }
+    }
+
+    pub fn evaluate(&self, t: f64) -> f64 {
+        if self.x.len() == 1 {
+            return self.y[0];
+        }
+        let i = segment(&self.x, t);
+        let h = self.x[i + 1] - self.x[i];
+        let (a, b) = ((self.x[i + 1] - t) / h, (t - self.x[i]) / h);
+        let (m0, m1) = (self.second_derivatives[i], self.second_derivatives[i + 1]);
+
+        a * self.y[i] + b * self.y[i + 1] + ((a.powi(3) - a) * m0 + (b.powi(3) - b) * m1) * h * h / 6.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_interpolation() {
+        let (x, y) = ([1.0, 2.0, 4.0], [1.0, 3.0, 4.0]);
+        assert_eq!(linear_interpolation(&x, &y, 1.5), 2.0);
+        assert_eq!(linear_interpolation(&x, &y, 3.0), 3.5);
+        assert_eq!(linear_interpolation(&x, &y, 5.0), 4.5);
+    }
+
+    #[test]
+    fn test_cubic_spline_reproduces_knots_and_cubics() {
+        let x: Vec<f64> = vec![0.0, 0.5, 1.5, 2.0, 3.0];
+        let y: Vec<f64> = x.iter().map(|t| 2.0 * t - 1.0).collect();
+        let spline = CubicSpline::new(x.clone(), y.clone());
+
+        for (&knot, &value) in x.iter().zip(y.iter()) {
+            assert!((spline.evaluate(knot) - value).abs() < 1e-12);
+        }
+        assert!((spline.evaluate(1.2) - 1.4).abs() < 1e-12);
+
+        let curved = CubicSpline::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0]);
+        assert!((curved.evaluate(0.5) - 0.6875).abs() < 1e-12);
+    }
+}
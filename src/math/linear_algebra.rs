@@ -0,0 +1,33 @@
+// Solves the dense system `a x = b` by Gaussian elimination with partial pivoting, or returns None if `a` is singular
+pub fn solve_linear_system(a: &[Vec<f64>], b: &[f64]) -> Option<Vec<f64>> {
+    let n = b.len();
+    let mut augmented: Vec<Vec<f64>> = a.iter()
+        .zip(b.iter())
+        .map(|(row, &rhs)| row.iter().copied().chain(std::iter::once(rhs)).collect())
+        .collect();
+
+    for column in 0..n {
+        let pivot = (column..n).max_by(|&i, &j| augmented[i][column].abs().total_cmp(&augmented[j][column].abs()))?;
+        if augmented[pivot][column].abs() < 1e-300 {
+            return None;
+        }
+        augmented.swap(column, pivot);
+
+        let (pivot_rows, remaining_rows) = augmented.split_at_mut(column + 1);
+        let pivot_row = &pivot_rows[column];
+        for row in remaining_rows.iter_mut() {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row[column..].iter_mut().zip(pivot_row[column..].iter()) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|k| augmented[row][k] * x[k]).sum();
+        x[row] = (augmented[row][n] - known) / augmented[row][row];
+    }
+
+    Some(x)
+}
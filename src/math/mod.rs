@@ -0,0 +1,4 @@
+pub mod linear_algebra;
+pub mod optimization;
+pub mod interpolation;
+pub mod root_finding;
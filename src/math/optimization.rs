@@ -0,0 +1,246 @@
+use crate::math::linear_algebra::solve_linear_system;
+
+#[derive(Clone, Debug)]
+pub struct OptimizationResult {
+    pub parameters: Vec<f64>,
+    pub cost: f64, // Objective value at the solution, half the sum of squared residuals for least squares
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct LevenbergMarquardtSettings {
+    pub max_iterations: usize,
+    pub tolerance: f64, // Relative tolerance on the cost decrease and step size
+    pub finite_difference_step: f64,
+}
+
+impl Default for LevenbergMarquardtSettings {
+    fn default() -> Self {
+        LevenbergMarquardtSettings { max_iterations: 200, tolerance: 1e-10, finite_difference_step: 1e-6 }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct NelderMeadSettings {
+    pub max_iterations: usize,
+    pub tolerance: f64, // Absolute tolerance on the spread of objective values across the simplex
+    pub initial_step: f64, // Relative size of the initial simplex around the initial guess
+}
+
+impl Default for NelderMeadSettings {
+    fn default() -> Self {
+        NelderMeadSettings { max_iterations: 5000, tolerance: 1e-10, initial_step: 0.1 }
+    }
+}
+
+fn cost(residuals: &[f64]) -> f64 {
+    0.5 * residuals.iter().map(|r| r * r).sum::<f64>()
+}
+
+fn project(parameters: &mut [f64], bounds: &[(f64, f64)]) {
+    for (parameter, &(lower, upper)) in parameters.iter_mut().zip(bounds.iter()) {
+        *parameter = parameter.clamp(lower, upper);
+    }
+}
+
+// Forward-difference Jacobian, stepping inwards at the upper bounds so that evaluations stay feasible
+fn jacobian<F: Fn(&[f64]) -> Vec<f64>>(residuals: &F, parameters: &[f64], current: &[f64], bounds: &[(f64, f64)], step: f64) -> Vec<Vec<f64>> {
+    let mut columns = Vec::with_capacity(parameters.len());
+    for k in 0..parameters.len() {
+        let mut h = step * parameters[k].abs().max(1.0);
+        if parameters[k] + h > bounds[k].1 {
+            h = -h;
+        }
+        let mut shifted = parameters.to_vec();
+        shifted[k] += h;
+        let shifted_residuals = residuals(&shifted);
+        columns.push(shifted_residuals.iter().zip(current.iter()).map(|(shifted, base)| (shifted - base) / h).collect::<Vec<f64>>());
+    }
+
+    // Transpose into one row per residual
+    (0..current.len()).map(|i| columns.iter().map(|column| column[i]).collect()).collect()
+}
+
+// Box-constrained Levenberg-Marquardt minimising the sum of squared `residuals`, with steps projected onto `bounds`
+pub fn levenberg_marquardt<F: Fn(&[f64]) -> Vec<f64>>(residuals: F, initial_guess: &[f64], bounds: &[(f64, f64)], settings: LevenbergMarquardtSettings) -> OptimizationResult {
+    let n = initial_guess.len();
+    let mut parameters = initial_guess.to_vec();
+    project(&mut parameters, bounds);
+
+    let mut current = residuals(&parameters);
+    let mut current_cost = cost(&current);
+    let mut damping = 1e-3;
+    let mut converged = false;
+    let mut iterations = 0;
+
+    while iterations < settings.max_iterations && !converged {
+        iterations += 1;
+        let j = jacobian(&residuals, &parameters, &current, bounds, settings.finite_difference_step);
+
+        let gradient: Vec<f64> = (0..n).map(|k| j.iter().zip(current.iter()).map(|(row, r)| row[k] * r).sum()).collect();
+        // Parameters held at a bound by the gradient are frozen for this iteration
+        let free: Vec<bool> = (0..n)
+            .map(|k| !((parameters[k] <= bounds[k].0 && gradient[k] > 0.0) || (parameters[k] >= bounds[k].1 && gradient[k] < 0.0)))
+            .collect();
+        let normal_matrix: Vec<Vec<f64>> = (0..n)
+            .map(|a| (0..n).map(|b| {
+                if free[a] && free[b] { j.iter().map(|row| row[a] * row[b]).sum() } else if a == b { 1.0 } else { 0.0 }
+            }).collect())
+            .collect();
+
+        loop {
+            let damped: Vec<Vec<f64>> = normal_matrix.iter()
+                .enumerate()
+                .map(|(a, row)| row.iter().enumerate().map(|(b, &value)| if a == b { value + damping * value.max(1e-12) } else { value }).collect())
+                .collect();
+            let negative_gradient: Vec<f64> = gradient.iter().zip(free.iter()).map(|(g, &free)| if free { -g } else { 0.0 }).collect();
+
+            let step = match solve_linear_system(&damped, &negative_gradient) {
+                Some(step) => step,
+                None => {
+                    damping *= 10.0;
+                    if damping > 1e16 { break; }
+                    continue;
+                }
+            };
+
+            let mut candidate: Vec<f64> = parameters.iter().zip(step.iter()).map(|(p, s)| p + s).collect();
+            project(&mut candidate, bounds);
+            let candidate_residuals = residuals(&candidate);
+            let candidate_cost = cost(&candidate_residuals);
+
+            if candidate_cost.is_finite() && candidate_cost < current_cost {
+                let step_size = candidate.iter().zip(parameters.iter()).map(|(c, p)| (c - p).abs() / p.abs().max(1.0)).fold(0.0, f64::max);
+                converged = current_cost - candidate_cost <= settings.tolerance * current_cost.max(1e-300) || step_size <= settings.tolerance;
+
+                parameters = candidate;
+                current = candidate_residuals;
+                current_cost = candidate_cost;
+                damping = (damping / 10.0).max(1e-12);
+                break;
+            }
+
+            damping *= 10.0;
+            if damping > 1e16 {
+                // No descent direction left within the bounds
+                converged = true;
+                break;
+            }
+        }
+    }
+
+    OptimizationResult { parameters, cost: current_cost, iterations, converged }
+}
+
+fn evaluate<F: Fn(&[f64]) -> f64>(objective: &F, point: &[f64]) -> f64 {
+    let value = objective(point);
+    if value.is_nan() { f64::INFINITY } else { value }
+}
+
+// Derivative-free Nelder-Mead simplex minimisation of `objective`, with every vertex projected onto `bounds`.
+// Infeasible regions can be excluded by returning infinity from the objective.
+pub fn nelder_mead<F: Fn(&[f64]) -> f64>(objective: F, initial_guess: &[f64], bounds: &[(f64, f64)], settings: NelderMeadSettings) -> OptimizationResult {
+    let n = initial_guess.len();
+    let mut origin = initial_guess.to_vec();
+    project(&mut origin, bounds);
+
+    let mut simplex: Vec<Vec<f64>> = vec![origin.clone()];
+    for k in 0..n {
+        let mut vertex = origin.clone();
+        let step = if vertex[k] != 0.0 { settings.initial_step * vertex[k] } else { settings.initial_step * 1e-2 };
+        vertex[k] += if vertex[k] + step > bounds[k].1 { -step } else { step };
+        project(&mut vertex, bounds);
+        simplex.push(vertex);
+    }
+    let mut values: Vec<f64> = simplex.iter().map(|vertex| evaluate(&objective, vertex)).collect();
+
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < settings.max_iterations {
+        iterations += 1;
+
+        let mut order: Vec<usize> = (0..=n).collect();
+        order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+        simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+        values = order.iter().map(|&i| values[i]).collect();
+
+        if values[n].is_finite() && (values[n] - values[0]).abs() <= settings.tolerance * (1.0 + values[0].abs()) {
+            converged = true;
+            break;
+        }
+
+        let centroid: Vec<f64> = (0..n).map(|k| simplex[..n].iter().map(|vertex| vertex[k]).sum::<f64>() / n as f64).collect();
+        let towards = |coefficient: f64| {
+            let mut point: Vec<f64> = centroid.iter().zip(simplex[n].iter()).map(|(c, worst)| c + coefficient * (c - worst)).collect();
+            project(&mut point, bounds);
+            point
+        };
+
+        let reflected = towards(1.0);
+        let reflected_value = evaluate(&objective, &reflected);
+
+        if reflected_value < values[0] {
+            let expanded = towards(2.0);
+            let expanded_value = evaluate(&objective, &expanded);
+            if expanded_value < reflected_value {
+                simplex[n] = expanded;
+                values[n] = expanded_value;
+            } else {
+                simplex[n] = reflected;
+                values[n] = reflected_value;
+            }
+        } else if reflected_value < values[n - 1] {
+            simplex[n] = reflected;
+            values[n] = reflected_value;
+        } else {
+            let contracted = if reflected_value < values[n] { towards(0.5) } else { towards(-0.5) };
+            let contracted_value = evaluate(&objective, &contracted);
+            if contracted_value < values[n].min(reflected_value) {
+                simplex[n] = contracted;
+                values[n] = contracted_value;
+            } else {
+                // Shrink every vertex towards the best one
+                for i in 1..=n {
+                    simplex[i] = simplex[0].iter().zip(simplex[i].iter()).map(|(best, vertex)| best + 0.5 * (vertex - best)).collect();
+                    values[i] = evaluate(&objective, &simplex[i]);
+                }
+            }
+        }
+    }
+
+    let best = (0..=n).min_by(|&a, &b| values[a].total_cmp(&values[b])).unwrap_or(0);
+    OptimizationResult { parameters: simplex[best].clone(), cost: values[best], iterations, converged }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rosenbrock() {
+        let residuals = |x: &[f64]| vec![10.0 * (x[1] - x[0].powi(2)), 1.0 - x[0]];
+        let result = levenberg_marquardt(residuals, &[-1.2, 1.0], &[(-5.0, 5.0), (-5.0, 5.0)], LevenbergMarquardtSettings::default());
+
+        assert!(result.converged);
+        assert!((result.parameters[0] - 1.0).abs() < 1e-6 && (result.parameters[1] - 1.0).abs() < 1e-6, "Solution {:?}", result.parameters);
+    }
+
+    #[test]
+    fn test_nelder_mead_rosenbrock() {
+        let objective = |x: &[f64]| 100.0 * (x[1] - x[0].powi(2)).powi(2) + (1.0 - x[0]).powi(2);
+        let settings = NelderMeadSettings { tolerance: 1e-16, ..NelderMeadSettings::default() };
+        let result = nelder_mead(objective, &[-1.2, 1.0], &[(-5.0, 5.0), (-5.0, 5.0)], settings);
+
+        assert!(result.converged);
+        assert!((result.parameters[0] - 1.0).abs() < 1e-4 && (result.parameters[1] - 1.0).abs() < 1e-4, "Solution {:?}", result.parameters);
+    }
+
+    #[test]
+    fn test_active_bound() {
+        let residuals = |x: &[f64]| vec![x[0] - 3.0];
+        let result = levenberg_marquardt(residuals, &[0.0], &[(-1.0, 2.0)], LevenbergMarquardtSettings::default());
+
+        assert!((result.parameters[0] - 2.0).abs() < 1e-12);
+    }
+}
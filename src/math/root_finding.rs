@@ -0,0 +1,71 @@
+// Widens the search over consecutive `grid` points until `f` changes sign, returning the first bracketing pair
+pub fn bracket_root<F: Fn(f64) -> f64>(f: F, grid: &[f64]) -> Option<(f64, f64)> {
+    let values: Vec<f64> = grid.iter().map(|&x| f(x)).collect();
+    (1..grid.len())
+        .find(|&i| values[i - 1].is_finite() && values[i].is_finite() && values[i - 1] * values[i] <= 0.0)
+        .map(|i| (grid[i - 1], grid[i]))
+}
+
+// Brent's method for a root of `f` in [lower, upper], which must bracket a sign change. Combines inverse quadratic
+// interpolation and secant steps with bisection so that convergence never falls behind bisection. `tolerance` is
+// relative to the root for roots larger than one, since doubles are coarser than an absolute tolerance there.
+pub fn brent<F: Fn(f64) -> f64>(f: F, lower: f64, upper: f64, tolerance: f64, max_iterations: usize) -> Option<f64> {
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa * fb > 0.0 {
+        return None;
+    }
+    if fa.abs() < fb.abs() {
+        (a, b, fa, fb) = (b, a, fb, fa);
+    }
+
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut bisected = true;
+    for _ in 0..max_iterations {
+        if fb == 0.0 || (b - a).abs() < tolerance * b.abs().max(1.0) {
+            return Some(b);
+        }
+
+        let mut s = if fa != fc && fb != fc {
+            a * fb * fc / ((fa - fb) * (fa - fc)) + b * fa * fc / ((fb - fa) * (fb - fc)) + c * fa * fb / ((fc - fa) * (fc - fb))
+        } else {
+            b - fb * (b - a) / (fb - fa)
+        };
+
+        let between = (s - (3.0 * a + b) / 4.0) * (s - b) < 0.0;
+        let slow = if bisected { (s - b).abs() >= (b - c).abs() / 2.0 } else { (s - b).abs() >= (c - d).abs() / 2.0 };
+        bisected = !between || slow;
+        if bisected {
+            s = 0.5 * (a + b);
+        }
+
+        let fs = f(s);
+        d = c;
+        (c, fc) = (b, fb);
+        if fa * fs < 0.0 { (b, fb) = (s, fs) } else { (a, fa) = (s, fs) }
+        if fa.abs() < fb.abs() {
+            (a, b, fa, fb) = (b, a, fb, fa);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brent() {
+        let root = brent(|x: f64| x.powi(3) - 2.0 * x - 5.0, 2.0, 3.0, 1e-14, 100).unwrap();
+        assert!((root - 2.0945514815423265).abs() < 1e-12);
+        assert!(brent(|x: f64| x * x + 1.0, -1.0, 1.0, 1e-14, 100).is_none());
+
+        // An absolute tolerance of 1e-14 is below the spacing of doubles near 100
+        let root = brent(|x: f64| x * x - 9000.0, 90.0, 110.0, 1e-14, 100).unwrap();
+        assert!((root - 9000f64.sqrt()).abs() < 1e-12);
+
+        assert_eq!(bracket_root(|x: f64| x - 0.3, &[-1.0, 0.0, 0.5, 1.0]), Some((0.0, 0.5)));
+    }
+}
@@ -1,33 +1,141 @@
 use chrono::Utc;
 use rand_distr::num_traits::Pow;
 
-use crate::instruments::OptionType;
+use crate::cashflows::DividendSchedule;
+use crate::curves::InterestRate;
+use crate::error::{check_count, check_finite_input, check_positive_input, QuantError};
+use crate::pricing::{check_option_inputs, time_to_maturity};
+use crate::instruments::{ExerciseStyle, OptionType};
 use crate::instruments::vanilla_option::VanillaOption;
 
-pub fn binomial_price(instrument: &VanillaOption, s0: f64, r: f64, sigma: f64, n: usize) -> f64 {
-    let time_to_maturity = instrument.exercise_datetime.signed_duration_since(Utc::now()).num_days() as f64 / 365.25;
+fn intrinsic_value(instrument: &VanillaOption, stock_price: f64) -> f64 {
+    if let OptionType::Call = instrument.option_type {
+        (stock_price - instrument.strike).max(0.0)
+    } else {
+        (instrument.strike - stock_price).max(0.0)
+    }
+}
+
+// Cox-Ross-Rubinstein tree on the dividend-escrowed price, with dividends still to be paid added back at each node
+pub fn binomial_price(instrument: &VanillaOption, s0: f64, r: f64, sigma: f64, n: usize, exercise_style: ExerciseStyle, dividends: &DividendSchedule) -> f64 {
+    let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
     let dt = time_to_maturity / n as f64;
     let up = (sigma * dt.sqrt()).exp();
     let down = 1.0 / up;
-    let p = (r.exp() * dt - down) / (up - down);
+    let growth = (r * dt).exp();
+    let p = (growth - down) / (up - down);
 
-    let mut price_tree = vec![0.0; n + 1];
+    let upcoming_dividends = dividends.upcoming(Utc::now());
+    let escrowed_s0 = dividends.escrowed_spot_between(s0, r, 0.0, time_to_maturity, &upcoming_dividends);
+    let stock_price = |i: usize, j: usize| {
+        let escrowed_price = escrowed_s0 * up.pow(j as i32) * down.pow(i as i32 - j as i32);
+        dividends.cum_dividend_spot_between(escrowed_price, r, i as f64 * dt, time_to_maturity, &upcoming_dividends)
+    };
 
-    for j in 0..=n {
-        let stock_price = s0 * up.pow(j as i32) * down.pow(n as i32 - j as i32);
-        price_tree[j] = if let OptionType::Call = instrument.option_type {
-            (stock_price - instrument.strike).max(0.0)
-        } else {
-            (instrument.strike - stock_price).max(0.0)
-        };
-    }
+    let mut price_tree: Vec<f64> = (0..=n).map(|j| intrinsic_value(instrument, stock_price(n, j))).collect();
 
     // Calculate the option price at each node
     for i in (0..n).rev() {
         for j in 0..=i {
-            price_tree[j] = ((p * price_tree[j + 1] + (1.0 - p) * price_tree[j]) / r.exp().pow(dt)).max(0.0);
+            let continuation_value = (p * price_tree[j + 1] + (1.0 - p) * price_tree[j]) / growth;
+            price_tree[j] = match exercise_style {
+                ExerciseStyle::European => continuation_value,
+                ExerciseStyle::American => continuation_value.max(intrinsic_value(instrument, stock_price(i, j))),
+            };
         }
     }
 
     price_tree[0]
-}
\ No newline at end of file
+}
+
+pub fn binomial_price_with_interest_rate(instrument: &VanillaOption, s0: f64, interest_rate: &InterestRate, sigma: f64, n: usize, exercise_style: ExerciseStyle, dividends: &DividendSchedule) -> f64 {
+    let r = interest_rate.continuous_rate_until(Utc::now(), instrument.exercise_datetime);
+    binomial_price(instrument, s0, r, sigma, n, exercise_style, dividends)
+}
+
+pub fn try_binomial_price(instrument: &VanillaOption, s0: f64, r: f64, sigma: f64, n: usize, exercise_style: ExerciseStyle, dividends: &DividendSchedule) -> Result<f64, QuantError> {
+    check_option_inputs(instrument.strike, instrument.exercise_datetime, s0)?;
+    check_finite_input("r", r)?;
+    check_positive_input("sigma", sigma)?;
+    check_count("binomial steps", n)?;
+    dividends.validate()?;
+    Ok(binomial_price(instrument, s0, r, sigma, n, exercise_style, dividends))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::cashflows::{Currency, Dividend};
+    use crate::pricing::black_scholes::{black_scholes_price, black_scholes_price_with_dividends};
+    use crate::processes::process_error::ProcessError;
+
+    use super::*;
+
+    fn create_option(option_type: OptionType, strike: f64) -> VanillaOption {
+        VanillaOption::new(strike, Utc::now() + Duration::days(365), Utc::now() + Duration::days(367), option_type, Currency::USD)
+    }
+
+    fn create_dividends() -> DividendSchedule {
+        DividendSchedule::new(vec![
+            Dividend::cash(3.0, Utc::now() + Duration::days(90)),
+            Dividend::proportional(0.02, Utc::now() + Duration::days(270)),
+        ])
+    }
+
+    #[test]
+    fn test_european_converges_to_black_scholes() {
+        let option = create_option(OptionType::Call, 100.0);
+        let price = binomial_price(&option, 100.0, 0.05, 0.2, 500, ExerciseStyle::European, &DividendSchedule::default());
+        let expected_price = black_scholes_price(&option, 100.0, 0.05, 0.2).amount;
+
+        assert!((price - expected_price).abs() < 0.01, "Price {} not close to Black-Scholes {}", price, expected_price);
+    }
+
+    #[test]
+    fn test_european_with_dividends_converges_to_escrowed_black_scholes() {
+        let option = create_option(OptionType::Put, 100.0);
+        let dividends = create_dividends();
+        let price = binomial_price(&option, 100.0, 0.05, 0.2, 500, ExerciseStyle::European, &dividends);
+        let expected_price = black_scholes_price_with_dividends(&option, 100.0, 0.05, 0.2, &dividends).amount;
+
+        assert!((price - expected_price).abs() < 0.02, "Price {} not close to Black-Scholes {}", price, expected_price);
+    }
+
+    #[test]
+    fn test_try_binomial_price_rejects_empty_tree() {
+        let option = create_option(OptionType::Put, 100.0);
+        let result = try_binomial_price(&option, 100.0, 0.05, 0.2, 0, ExerciseStyle::American, &DividendSchedule::default());
+
+        assert_eq!(result, Err(QuantError::EmptyInput { name: "binomial steps" }));
+
+        // A ratio of one would divide by zero when adding the dividend back onto the escrowed price
+        let dividends = DividendSchedule::new(vec![Dividend::proportional(1.0, Utc::now() + Duration::days(90))]);
+        let result = try_binomial_price(&option, 100.0, 0.05, 0.2, 100, ExerciseStyle::American, &dividends);
+        assert!(matches!(result, Err(QuantError::Process(ProcessError::InvalidParameter { name: "dividend ratio", .. }))));
+    }
+
+    #[test]
+    fn test_american_call_without_dividends_is_european() {
+        let option = create_option(OptionType::Call, 100.0);
+        let american_price = binomial_price(&option, 100.0, 0.05, 0.2, 200, ExerciseStyle::American, &DividendSchedule::default());
+        let european_price = binomial_price(&option, 100.0, 0.05, 0.2, 200, ExerciseStyle::European, &DividendSchedule::default());
+
+        assert!((american_price - european_price).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_american_premium_with_dividends() {
+        let call = create_option(OptionType::Call, 80.0);
+        let put = create_option(OptionType::Put, 120.0);
+        let dividends = create_dividends();
+
+        let american_call = binomial_price(&call, 100.0, 0.05, 0.2, 200, ExerciseStyle::American, &dividends);
+        let european_call = binomial_price(&call, 100.0, 0.05, 0.2, 200, ExerciseStyle::European, &dividends);
+        let american_put = binomial_price(&put, 100.0, 0.05, 0.2, 200, ExerciseStyle::American, &dividends);
+        let european_put = binomial_price(&put, 100.0, 0.05, 0.2, 200, ExerciseStyle::European, &dividends);
+
+        assert!(american_call > european_call, "Early exercise before the dividend should add value to the call");
+        assert!(american_put > european_put, "Early exercise should add value to the in-the-money put");
+    }
+}
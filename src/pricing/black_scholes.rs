@@ -3,22 +3,25 @@ extern crate statrs;
 
 use chrono::offset::Utc;
 use statrs::distribution::{Continuous, ContinuousCDF, Normal};
-use crate::cashflows::CashFlow;
+use crate::cashflows::{CashFlow, DividendSchedule};
 
 use crate::instruments::OptionType;
 use crate::instruments::vanilla_option::VanillaOption;
+use crate::curves::InterestRate;
+use crate::error::{check_finite_input, check_positive_input, QuantError};
+use crate::pricing::{check_option_inputs, time_to_maturity};
 
-fn normal_cdf(x: f64) -> f64 {
+pub(crate) fn normal_cdf(x: f64) -> f64 {
     let normal = Normal::new(0.0, 1.0).unwrap();
     normal.cdf(x)
 }
 
-fn normal_pdf(x: f64) -> f64 {
+pub(crate) fn normal_pdf(x: f64) -> f64 {
     let normal = Normal::new(0.0, 1.0).unwrap();
     normal.pdf(x)
 }
 
-fn d1_d2(strike: f64, s0: f64, r: f64, sigma: f64, time_to_maturity: f64) -> (f64, f64){
+pub(crate) fn d1_d2(strike: f64, s0: f64, r: f64, sigma: f64, time_to_maturity: f64) -> (f64, f64){
     let d1 = ((s0 / strike).ln() + (r + sigma.powi(2) / 2.0) * time_to_maturity) / (sigma * time_to_maturity.sqrt());
     let d2 = d1 - sigma * time_to_maturity.sqrt();
 
@@ -28,7 +31,7 @@ fn d1_d2(strike: f64, s0: f64, r: f64, sigma: f64, time_to_maturity: f64) -> (f6
 macro_rules! create_black_scholes_functions {
     ($option_type:ty) => {
         pub fn black_scholes_price(instrument: &$option_type, s0: f64, r: f64, sigma: f64) -> CashFlow {
-            let time_to_maturity = instrument.exercise_datetime.signed_duration_since(Utc::now()).num_days() as f64 / 365.25;
+            let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
             let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, time_to_maturity);
 
             let option_price = match instrument.option_type {
@@ -39,8 +42,48 @@ macro_rules! create_black_scholes_functions {
             CashFlow::new(option_price, instrument.underlying_currency, Utc::now())
         }
 
+        pub fn try_black_scholes_price(instrument: &$option_type, s0: f64, r: f64, sigma: f64) -> Result<CashFlow, QuantError> {
+            check_option_inputs(instrument.strike, instrument.exercise_datetime, s0)?;
+            check_finite_input("r", r)?;
+            check_positive_input("sigma", sigma)?;
+            Ok(black_scholes_price(instrument, s0, r, sigma))
+        }
+
+        // Volatility reproducing `price`, found by Newton iterations safeguarded by bisection
+        pub fn implied_volatility(instrument: &$option_type, price: f64, s0: f64, r: f64) -> Option<f64> {
+            let (mut lower, mut upper) = (1e-6, 5.0);
+            if price < black_scholes_price(instrument, s0, r, lower).amount || price > black_scholes_price(instrument, s0, r, upper).amount {
+                return None;
+            }
+
+            let mut sigma = 0.2;
+            for _ in 0..100 {
+                let error = black_scholes_price(instrument, s0, r, sigma).amount - price;
+                if error.abs() < 1e-10 {
+                    return Some(sigma);
+                }
+                if error > 0.0 { upper = sigma } else { lower = sigma }
+
+                let newton_step = sigma - error / vega(instrument, s0, r, sigma);
+                sigma = if newton_step > lower && newton_step < upper { newton_step } else { 0.5 * (lower + upper) };
+            }
+
+            Some(sigma)
+        }
+
+        // Prices with the continuously compounded rate equivalent to `interest_rate` up to expiry
+        pub fn black_scholes_price_with_interest_rate(instrument: &$option_type, s0: f64, interest_rate: &InterestRate, sigma: f64) -> CashFlow {
+            let r = interest_rate.continuous_rate_until(Utc::now(), instrument.exercise_datetime);
+            black_scholes_price(instrument, s0, r, sigma)
+        }
+
+        pub fn black_scholes_price_with_dividends(instrument: &$option_type, s0: f64, r: f64, sigma: f64, dividends: &DividendSchedule) -> CashFlow {
+            let escrowed_s0 = dividends.escrowed_spot(s0, r, Utc::now(), instrument.exercise_datetime);
+            black_scholes_price(instrument, escrowed_s0, r, sigma)
+        }
+
         pub fn delta(instrument: &$option_type, s0: f64, r: f64, sigma: f64) -> f64 {
-            let time_to_maturity = instrument.exercise_datetime.signed_duration_since(Utc::now()).num_days() as f64 / 365.25;
+            let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
             let (d1, _) = d1_d2(instrument.strike, s0, r, sigma, time_to_maturity);
 
             match instrument.option_type {
@@ -50,19 +93,19 @@ macro_rules! create_black_scholes_functions {
         }
 
         pub fn gamma(instrument: &$option_type, s0: f64, r: f64, sigma: f64) -> f64 {
-            let time_to_maturity = instrument.exercise_datetime.signed_duration_since(Utc::now()).num_days() as f64 / 365.25;
+            let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
             let (d1, _) = d1_d2(instrument.strike, s0, r, sigma, time_to_maturity);
             normal_pdf(d1) / (s0 * sigma * time_to_maturity.sqrt())
         }
 
         pub fn vega(instrument: &$option_type, s0: f64, r: f64, sigma: f64) -> f64 {
-            let time_to_maturity = instrument.exercise_datetime.signed_duration_since(Utc::now()).num_days() as f64 / 365.25;
+            let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
             let (d1, _) = d1_d2(instrument.strike, s0, r, sigma, time_to_maturity);
             s0 * normal_pdf(d1) * time_to_maturity.sqrt()
         }
 
         pub fn theta(instrument: &$option_type, s0: f64, r: f64, sigma: f64) -> f64 {
-            let time_to_maturity = instrument.exercise_datetime.signed_duration_since(Utc::now()).num_days() as f64 / 365.25;
+            let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
             let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, time_to_maturity);
 
             match instrument.option_type {
@@ -72,7 +115,7 @@ macro_rules! create_black_scholes_functions {
         }
 
         pub fn rho(instrument: &$option_type, s0: f64, r: f64, sigma: f64) -> f64 {
-            let time_to_maturity = instrument.exercise_datetime.signed_duration_since(Utc::now()).num_days() as f64 / 365.25;
+            let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
             let (_, d2) = d1_d2(instrument.strike, s0, r, sigma, time_to_maturity);
 
             match instrument.option_type {
@@ -85,9 +128,12 @@ macro_rules! create_black_scholes_functions {
 
 create_black_scholes_functions!(VanillaOption);
 
+#[cfg(test)]
 mod tests {
     use chrono::Duration;
     use crate::cashflows::currency::Currency;
+    use crate::curves::Compounding;
+    use crate::time::DayCount;
 
     use super::*;
 
@@ -155,12 +201,42 @@ mod tests {
         assert!((theta - -6.41403).abs() < 0.1);
     }
 
+    #[test]
+    fn test_try_price_rejects_expired_option() {
+        let expired = create_option(OptionType::Call, 100.0, -10, Currency::USD);
+        assert!(matches!(try_black_scholes_price(&expired, 100.0, 0.05, 0.2), Err(QuantError::ExpiredInstrument { .. })));
+
+        let option = create_option(OptionType::Call, 100.0, 365, Currency::USD);
+        assert!(matches!(try_black_scholes_price(&option, 100.0, 0.05, 0.0), Err(QuantError::InvalidInput { name: "sigma", .. })));
+        assert_eq!(try_black_scholes_price(&option, 100.0, 0.05, 0.2).unwrap().amount, black_scholes_price(&option, 100.0, 0.05, 0.2).amount);
+    }
+
+    #[test]
+    fn test_price_with_annually_compounded_rate() {
+        let option = create_option(OptionType::Call, 100.0, 365, Currency::USD);
+        let annual_rate = InterestRate::new(0.05f64.exp_m1(), DayCount::Actual365Fixed, Compounding::Periodic(1));
+
+        let price = black_scholes_price_with_interest_rate(&option, 100.0, &annual_rate, 0.2);
+        let expected_price = black_scholes_price(&option, 100.0, 0.05, 0.2);
+        assert!((price.amount - expected_price.amount).abs() < 1e-10);
+    }
+
     #[test]
     fn test_rho() {
         let option = create_option(OptionType::Call, 100.0, 365, Currency::USD);
         let rho = rho(&option, 100.0, 0.05, 0.2);
 
-        assert!((rho - 53.04977330181251).abs() < 0.1);
+        assert!((rho - 53.232481545376345).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_implied_volatility() {
+        let option = create_option(OptionType::Put, 110.0, 365, Currency::USD);
+        let price = black_scholes_price(&option, 100.0, 0.05, 0.27).amount;
+
+        let sigma = implied_volatility(&option, price, 100.0, 0.05).unwrap();
+        assert!((sigma - 0.27).abs() < 1e-8);
+        assert!(implied_volatility(&option, 0.0, 100.0, 0.05).is_none());
     }
 
     #[test]
@@ -0,0 +1,204 @@
+use chrono::offset::Utc;
+
+use crate::cashflows::CashFlow;
+use crate::error::{check_finite_input, check_positive_input, QuantError};
+use crate::instruments::digital_option::{DigitalOption, DigitalPayoff};
+use crate::instruments::{OptionType, Value};
+use crate::pricing::black_scholes::{d1_d2, normal_cdf, normal_pdf};
+use crate::pricing::{check_option_inputs, time_to_maturity};
+
+fn sign(instrument: &DigitalOption) -> f64 {
+    match instrument.option_type { OptionType::Call => 1.0, OptionType::Put => -1.0 }
+}
+
+// Closed-form Black-Scholes prices and greeks of digital options, for time to expiry `t`. Cash-or-nothing digitals
+// pay off on N(d2) under the risk-neutral measure and asset-or-nothing digitals on N(d1) under the stock measure.
+fn price_for(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64, t: f64) -> f64 {
+    let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, t);
+    let sign = sign(instrument);
+
+    match instrument.payoff {
+        DigitalPayoff::CashOrNothing(cash) => cash * (-r * t).exp() * normal_cdf(sign * d2),
+        DigitalPayoff::AssetOrNothing => s0 * normal_cdf(sign * d1),
+    }
+}
+
+pub fn black_scholes_price(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64) -> CashFlow {
+    let price = price_for(instrument, s0, r, sigma, time_to_maturity(instrument.exercise_datetime));
+    CashFlow::new(price, instrument.underlying_currency, Utc::now())
+}
+
+pub fn try_black_scholes_price(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64) -> Result<CashFlow, QuantError> {
+    check_option_inputs(instrument.strike, instrument.exercise_datetime, s0)?;
+    check_finite_input("r", r)?;
+    check_positive_input("sigma", sigma)?;
+    instrument.validate()?;
+    Ok(black_scholes_price(instrument, s0, r, sigma))
+}
+
+pub fn delta(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64) -> f64 {
+    let t = time_to_maturity(instrument.exercise_datetime);
+    let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, t);
+    let sign = sign(instrument);
+
+    match instrument.payoff {
+        DigitalPayoff::CashOrNothing(cash) => sign * cash * (-r * t).exp() * normal_pdf(d2) / (s0 * sigma * t.sqrt()),
+        DigitalPayoff::AssetOrNothing => normal_cdf(sign * d1) + sign * normal_pdf(d1) / (sigma * t.sqrt()),
+    }
+}
+
+pub fn gamma(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64) -> f64 {
+    let t = time_to_maturity(instrument.exercise_datetime);
+    let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, t);
+    let sign = sign(instrument);
+
+    match instrument.payoff {
+        DigitalPayoff::CashOrNothing(cash) => -sign * cash * (-r * t).exp() * normal_pdf(d2) * d1 / (s0 * s0 * sigma * sigma * t),
+        DigitalPayoff::AssetOrNothing => -sign * normal_pdf(d1) * d2 / (s0 * sigma * sigma * t),
+    }
+}
+
+pub fn vega(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64) -> f64 {
+    let t = time_to_maturity(instrument.exercise_datetime);
+    let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, t);
+    let sign = sign(instrument);
+
+    match instrument.payoff {
+        DigitalPayoff::CashOrNothing(cash) => -sign * cash * (-r * t).exp() * normal_pdf(d2) * d1 / sigma,
+        DigitalPayoff::AssetOrNothing => -sign * s0 * normal_pdf(d1) * d2 / sigma,
+    }
+}
+
+// Change in value per year of calendar time passing
+fn theta_for(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64, t: f64) -> f64 {
+    let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, t);
+    let sign = sign(instrument);
+
+    match instrument.payoff {
+        DigitalPayoff::CashOrNothing(cash) => {
+            let d2_dt = (r - 0.5 * sigma * sigma) / (sigma * t.sqrt()) - d2 / (2.0 * t);
+            r * price_for(instrument, s0, r, sigma, t) - sign * cash * (-r * t).exp() * normal_pdf(d2) * d2_dt
+        }
+        DigitalPayoff::AssetOrNothing => {
+            let d1_dt = (r + 0.5 * sigma * sigma) / (sigma * t.sqrt()) - d1 / (2.0 * t);
+            -sign * s0 * normal_pdf(d1) * d1_dt
+        }
+    }
+}
+
+pub fn theta(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64) -> f64 {
+    theta_for(instrument, s0, r, sigma, time_to_maturity(instrument.exercise_datetime))
+}
+
+pub fn rho(instrument: &DigitalOption, s0: f64, r: f64, sigma: f64) -> f64 {
+    let t = time_to_maturity(instrument.exercise_datetime);
+    let (d1, d2) = d1_d2(instrument.strike, s0, r, sigma, t);
+    let sign = sign(instrument);
+
+    match instrument.payoff {
+        DigitalPayoff::CashOrNothing(cash) => cash * (-r * t).exp() * (-t * normal_cdf(sign * d2) + sign * normal_pdf(d2) * t.sqrt() / sigma),
+        DigitalPayoff::AssetOrNothing => sign * s0 * normal_pdf(d1) * t.sqrt() / sigma,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::cashflows::Currency;
+    use crate::instruments::vanilla_option::VanillaOption;
+    use crate::pricing::black_scholes;
+    use crate::pricing::monte_carlo::try_monte_carlo_price;
+    use crate::processes::black_scholes_process::BlackScholesProcess;
+    use super::*;
+
+    fn create_option(option_type: OptionType, payoff: DigitalPayoff) -> DigitalOption {
+        let expiry = Utc::now() + Duration::days(365);
+        DigitalOption::new(100.0, expiry, expiry, option_type, payoff, Currency::USD)
+    }
+
+    const PAYOFFS: [DigitalPayoff; 2] = [DigitalPayoff::CashOrNothing(10.0), DigitalPayoff::AssetOrNothing];
+
+    #[test]
+    fn test_known_prices() {
+        let (s0, r, sigma) = (100.0, 0.05, 0.2);
+        let cash_call = black_scholes_price(&create_option(OptionType::Call, DigitalPayoff::CashOrNothing(1.0)), s0, r, sigma).amount;
+        assert!((cash_call - 0.5323248154537634).abs() < 1e-6);
+
+        // Asset-or-nothing minus strike times cash-or-nothing is the vanilla call
+        let option = create_option(OptionType::Call, DigitalPayoff::AssetOrNothing);
+        let asset_call = black_scholes_price(&option, s0, r, sigma).amount;
+        let vanilla_option = VanillaOption::new(option.strike, option.exercise_datetime, option.settlement_datetime, OptionType::Call, Currency::USD);
+        let vanilla = black_scholes::black_scholes_price(&vanilla_option, s0, r, sigma).amount;
+        assert!((asset_call - 100.0 * cash_call - vanilla).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let (s0, r, sigma) = (95.0, 0.03, 0.25);
+        let price = |option_type, payoff| black_scholes_price(&create_option(option_type, payoff), s0, r, sigma).amount;
+        let t = time_to_maturity(create_option(OptionType::Call, PAYOFFS[0]).exercise_datetime);
+
+        assert!((price(OptionType::Call, PAYOFFS[0]) + price(OptionType::Put, PAYOFFS[0]) - 10.0 * (-r * t).exp()).abs() < 1e-9);
+        assert!((price(OptionType::Call, PAYOFFS[1]) + price(OptionType::Put, PAYOFFS[1]) - s0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_greeks_match_finite_differences() {
+        let (s0, r, sigma, h) = (105.0, 0.04, 0.3, 1e-4);
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for payoff in PAYOFFS {
+                let option = create_option(option_type, payoff);
+                let t = time_to_maturity(option.exercise_datetime);
+                let price = |s0: f64, r: f64, sigma: f64, t: f64| price_for(&option, s0, r, sigma, t);
+                let context = format!("{:?} {:?}", option_type, payoff);
+
+                let bumped_delta = (price(s0 + h, r, sigma, t) - price(s0 - h, r, sigma, t)) / (2.0 * h);
+                let bumped_gamma = (price(s0 + h, r, sigma, t) - 2.0 * price(s0, r, sigma, t) + price(s0 - h, r, sigma, t)) / (h * h);
+                let bumped_vega = (price(s0, r, sigma + h, t) - price(s0, r, sigma - h, t)) / (2.0 * h);
+                let bumped_rho = (price(s0, r + h, sigma, t) - price(s0, r - h, sigma, t)) / (2.0 * h);
+                let bumped_theta = -(price(s0, r, sigma, t + h) - price(s0, r, sigma, t - h)) / (2.0 * h);
+
+                assert!((delta(&option, s0, r, sigma) - bumped_delta).abs() < 1e-6, "delta {}", context);
+                assert!((gamma(&option, s0, r, sigma) - bumped_gamma).abs() < 1e-4, "gamma {}", context);
+                assert!((vega(&option, s0, r, sigma) - bumped_vega).abs() < 1e-5, "vega {}", context);
+                assert!((rho(&option, s0, r, sigma) - bumped_rho).abs() < 1e-5, "rho {}", context);
+                assert!((theta_for(&option, s0, r, sigma, t) - bumped_theta).abs() < 1e-5, "theta {}", context);
+            }
+        }
+    }
+
+    #[test]
+    fn test_call_spread_replication_converges() {
+        let (s0, r, sigma) = (100.0, 0.05, 0.2);
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for payoff in PAYOFFS {
+                let option = create_option(option_type, payoff);
+                let replicated: f64 = option.call_spread_replication(0.01).iter()
+                    .map(|(weight, vanilla)| weight * black_scholes::black_scholes_price(vanilla, s0, r, sigma).amount)
+                    .sum();
+                let exact = black_scholes_price(&option, s0, r, sigma).amount;
+                assert!((replicated - exact).abs() < 1e-4, "{:?} {:?}: {} against {}", option_type, payoff, replicated, exact);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let option = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(f64::NAN));
+        assert!(matches!(try_black_scholes_price(&option, 100.0, 0.05, 0.2), Err(QuantError::InvalidInput { name: "cash", .. })));
+        assert!(try_black_scholes_price(&create_option(OptionType::Put, DigitalPayoff::AssetOrNothing), 100.0, 0.05, -0.2).is_err());
+
+        // Zero or negative call spread widths would divide the replication weights by zero or flip their sign
+        let process = BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0);
+        for width in [0.0, -1.0, f64::NAN] {
+            let smoothed = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(10.0)).with_call_spread(width);
+            assert!(matches!(try_black_scholes_price(&smoothed, 100.0, 0.05, 0.2), Err(QuantError::InvalidInput { name: "call_spread_width", .. })));
+            assert!(matches!(try_monte_carlo_price(&smoothed, &process, 0.05, 10, 10), Err(QuantError::InvalidInput { name: "call_spread_width", .. })));
+        }
+        let smoothed = create_option(OptionType::Call, DigitalPayoff::CashOrNothing(10.0)).with_call_spread(0.5);
+        assert!(try_monte_carlo_price(&smoothed, &process, 0.05, 10, 10).unwrap().amount.is_finite());
+    }
+}
@@ -0,0 +1,129 @@
+use std::f64::consts::PI;
+
+use chrono::Utc;
+use num_complex::Complex;
+
+use crate::cashflows::CashFlow;
+use crate::instruments::OptionType;
+use crate::instruments::vanilla_option::VanillaOption;
+use crate::error::QuantError;
+use crate::pricing::{check_option_inputs, time_to_maturity};
+use crate::processes::{CharacteristicFunction, Simulate};
+
+const INTEGRATION_UPPER_BOUND: f64 = 200.0;
+const PANEL_WIDTH: f64 = 5.0;
+const PANEL_INTERVALS: usize = 100;
+const TRUNCATION_TOLERANCE: f64 = 1e-12;
+
+// Composite Simpson rule on (0, INTEGRATION_UPPER_BOUND], starting just off the removable singularity at zero and
+// stopping early once the integrand stays below TRUNCATION_TOLERANCE over a whole panel
+fn integrate<F: Fn(f64) -> f64>(f: F) -> f64 {
+    let h = PANEL_WIDTH / PANEL_INTERVALS as f64;
+    let mut total = 0.0;
+    let mut lower = 1e-8;
+
+    while lower < INTEGRATION_UPPER_BOUND {
+        let values: Vec<f64> = (0..=PANEL_INTERVALS).map(|j| f(lower + j as f64 * h)).collect();
+        let weighted_sum: f64 = values.iter()
+            .enumerate()
+            .map(|(j, value)| if j == 0 || j == PANEL_INTERVALS { 1.0 } else if j % 2 == 0 { 2.0 } else { 4.0 } * value)
+            .sum();
+        total += weighted_sum * h / 3.0;
+
+        if values.iter().all(|value| value.abs() < TRUNCATION_TOLERANCE) {
+            break;
+        }
+        lower += PANEL_WIDTH;
+    }
+
+    total
+}
+
+// Gil-Pelaez inversion for the share-measure and risk-neutral exercise probabilities P1 and P2
+fn exercise_probabilities<P: CharacteristicFunction>(process: &P, strike: f64, time_to_maturity: f64, forward: f64) -> (f64, f64) {
+    let i: Complex<f64> = Complex::i();
+    let log_strike = strike.ln();
+
+    let p1 = 0.5 + integrate(|u| {
+        let u = Complex::new(u, 0.0);
+        ((-i * u * log_strike).exp() * process.characteristic_function(u - i, time_to_maturity) / (i * u * forward)).re
+    }) / PI;
+
+    let p2 = 0.5 + integrate(|u| {
+        let u = Complex::new(u, 0.0);
+        ((-i * u * log_strike).exp() * process.characteristic_function(u, time_to_maturity) / (i * u)).re
+    }) / PI;
+
+    (p1, p2)
+}
+
+pub fn characteristic_function_price<P: CharacteristicFunction>(instrument: &VanillaOption, process: &P) -> CashFlow {
+    let time_to_maturity = time_to_maturity(instrument.exercise_datetime);
+    let discount_factor = (-process.risk_free_rate() * time_to_maturity).exp();
+
+    // The forward is taken from the characteristic function so that dividends modelled by the process are priced in
+    let forward = process.characteristic_function(-Complex::i(), time_to_maturity).re;
+    let (p1, p2) = exercise_probabilities(process, instrument.strike, time_to_maturity, forward);
+
+    let call_price = discount_factor * (forward * p1 - instrument.strike * p2);
+    let option_price = match instrument.option_type {
+        OptionType::Call => call_price,
+        OptionType::Put => call_price - discount_factor * (forward - instrument.strike),
+    };
+
+    CashFlow::new(option_price, instrument.underlying_currency, Utc::now())
+}
+
+pub fn try_characteristic_function_price<P: CharacteristicFunction + Simulate>(instrument: &VanillaOption, process: &P) -> Result<CashFlow, QuantError> {
+    check_option_inputs(instrument.strike, instrument.exercise_datetime, process.initial_price())?;
+    process.validate()?;
+    Ok(characteristic_function_price(instrument, process))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::cashflows::Currency;
+    use crate::pricing::black_scholes::black_scholes_price;
+    use crate::pricing::monte_carlo::monte_carlo_price;
+    use crate::processes::normal_inverse_gaussian_process::NormalInverseGaussianProcess;
+    use crate::processes::variance_gamma_process::VarianceGammaProcess;
+
+    use super::*;
+
+    fn create_option(option_type: OptionType, strike: f64) -> VanillaOption {
+        let exercise_datetime = Utc::now() + Duration::days(365);
+        VanillaOption::new(strike, exercise_datetime, exercise_datetime, option_type, Currency::USD)
+    }
+
+    #[test]
+    fn test_variance_gamma_converges_to_black_scholes() {
+        let option = create_option(OptionType::Call, 105.0);
+        let process = VarianceGammaProcess::new(100.0, 0.05, 0.2, 1e-4, 0.0, 1.0);
+
+        let price = characteristic_function_price(&option, &process);
+        let expected_price = black_scholes_price(&option, 100.0, 0.05, 0.2);
+        assert!((price.amount - expected_price.amount).abs() < 0.01, "Price {} not close to Black-Scholes {}", price, expected_price);
+    }
+
+    #[test]
+    fn test_variance_gamma_matches_monte_carlo() {
+        let option = create_option(OptionType::Call, 100.0);
+        let process = VarianceGammaProcess::new(100.0, 0.05, 0.2, 0.3, -0.15, 1.0);
+
+        let price = characteristic_function_price(&option, &process);
+        let mc_price = monte_carlo_price(&option, &process, 0.05f64.exp_m1(), 40000, 4);
+        assert!((price.amount - mc_price.amount).abs() < 0.25, "Price {} too far from Monte Carlo {}", price, mc_price);
+    }
+
+    #[test]
+    fn test_normal_inverse_gaussian_matches_monte_carlo() {
+        let option = create_option(OptionType::Put, 100.0);
+        let process = NormalInverseGaussianProcess::new(100.0, 0.05, 15.0, -5.0, 0.5, 1.0);
+
+        let price = characteristic_function_price(&option, &process);
+        let mc_price = monte_carlo_price(&option, &process, 0.05f64.exp_m1(), 40000, 4);
+        assert!((price.amount - mc_price.amount).abs() < 0.25, "Price {} too far from Monte Carlo {}", price, mc_price);
+    }
+}
@@ -1,3 +1,27 @@
-mod black_scholes;
-mod binomial;
-pub mod monte_carlo;
\ No newline at end of file
+pub mod black_scholes;
+pub mod binomial;
+pub mod monte_carlo;
+pub mod fourier;
+pub mod digital;
+
+use chrono::{DateTime, Utc};
+use crate::error::{check_positive_input, QuantError};
+use crate::time::DayCount;
+
+// Option time to expiry is measured Act/365F from the current time
+pub(crate) fn time_to_maturity(exercise_datetime: DateTime<Utc>) -> f64 {
+    DayCount::Actual365Fixed.year_fraction(Utc::now(), exercise_datetime)
+}
+
+// Returns the time to expiry of an instrument that has not expired yet
+pub(crate) fn check_expiry(exercise_datetime: DateTime<Utc>) -> Result<f64, QuantError> {
+    let time_to_maturity = time_to_maturity(exercise_datetime);
+    if time_to_maturity > 0.0 { Ok(time_to_maturity) } else { Err(QuantError::ExpiredInstrument { exercise_datetime }) }
+}
+
+// Validates the inputs shared by the vanilla pricers and returns the time to expiry
+pub(crate) fn check_option_inputs(strike: f64, exercise_datetime: DateTime<Utc>, s0: f64) -> Result<f64, QuantError> {
+    check_positive_input("strike", strike)?;
+    check_positive_input("s0", s0)?;
+    check_expiry(exercise_datetime)
+}
@@ -1,15 +1,100 @@
 use chrono::Utc;
 use crate::cashflows::CashFlow;
+use crate::curves::InterestRate;
+use crate::error::{check_count, check_finite_input, QuantError};
 use crate::instruments::Value;
-use crate::processes::Simulate;
+use crate::processes::{ShortRateProcess, Simulate};
+use crate::pricing::{check_expiry, time_to_maturity};
+use crate::processes::simulated_path::{SimulatedPath, StateVariable};
+
+// Average of the simulated payoffs, accumulated from a zero cashflow in the instrument's currency and on its settlement
+// date rather than relying on the `Sum` of cashflows
+fn mean_payoff<T: Value, I: Iterator<Item = CashFlow>>(instrument: &T, payoffs: I, number_of_paths: usize) -> CashFlow {
+    let zero = CashFlow::new(0.0, instrument.underlying_currency(), instrument.settlement_datetime());
+    payoffs.fold(zero, |total, payoff| &total + &payoff) / (number_of_paths as f64)
+}
 
 pub fn monte_carlo_price<T: Value, U: Simulate>(instrument: &T, price_process: &U, annual_discount_rate: f64, number_of_paths: usize, number_of_steps: usize) -> CashFlow
 {
-    ((0..number_of_paths)
-        .map(|_| price_process.generate_price_path(number_of_steps))
-        .map(|price_path| instrument.calculate_payoff(&price_path))
-        .sum::<CashFlow>() / (number_of_paths as f64))
-        .value_at_date(Utc::now(), annual_discount_rate)
+    let payoffs = (0..number_of_paths).map(|_| instrument.calculate_path_payoff(&price_process.generate_path(number_of_steps)));
+    mean_payoff(instrument, payoffs, number_of_paths).value_at_date(Utc::now(), annual_discount_rate)
+}
+
+pub fn monte_carlo_price_with_interest_rate<T: Value, U: Simulate>(instrument: &T, price_process: &U, interest_rate: &InterestRate, number_of_paths: usize, number_of_steps: usize) -> CashFlow
+{
+    let payoffs = (0..number_of_paths).map(|_| instrument.calculate_path_payoff(&price_process.generate_path(number_of_steps)));
+    mean_payoff(instrument, payoffs, number_of_paths).value_at_date_with_rate(Utc::now(), interest_rate)
+}
+
+// Discount factor from now to `maturity` along a simulated short rate path. It is realized up to the last simulated
+// step on or before maturity and continued with the model zero-coupon bond price, which is exact in expectation, so
+// the instrument settlement need not coincide with the time horizon of the short rate process.
+fn path_discount_factor<V: ShortRateProcess>(short_rate_process: &V, short_rate_path: &SimulatedPath, maturity: f64) -> Option<f64> {
+    let short_rates = short_rate_path.state(StateVariable::ShortRate)?;
+    let numeraires = short_rate_path.state(StateVariable::Numeraire)?;
+    let dt = short_rate_process.time_horizon() / short_rates.len() as f64;
+
+    let step = ((maturity.max(0.0) / dt + 1e-9).floor() as usize).min(short_rates.len());
+    let (time, short_rate, discount_factor) = match step {
+        0 => (0.0, short_rate_process.initial_rate(), 1.0),
+        _ => (step as f64 * dt, short_rates[step - 1], 1.0 / numeraires[step - 1]),
+    };
+
+    Some(discount_factor * short_rate_process.zero_coupon_bond_price(time, maturity.max(time), short_rate))
+}
+
+pub fn monte_carlo_price_with_short_rate<T: Value, U: Simulate, V: ShortRateProcess>(instrument: &T, price_process: &U, short_rate_process: &V, number_of_paths: usize, number_of_steps: usize) -> CashFlow
+{
+    let maturity = time_to_maturity(instrument.settlement_datetime());
+    let discounted_payoffs = (0..number_of_paths)
+        .map(|_| {
+            let path = price_process.generate_path(number_of_steps);
+            let short_rate_path = short_rate_process.generate_path(number_of_steps);
+            let discount_factor = path_discount_factor(short_rate_process, &short_rate_path, maturity).unwrap_or(1.0);
+
+            instrument.calculate_path_payoff(&path) * discount_factor
+        });
+    let discounted_payoff = mean_payoff(instrument, discounted_payoffs, number_of_paths);
+
+    CashFlow::new(discounted_payoff.amount, instrument.underlying_currency(), Utc::now())
+}
+
+pub fn try_monte_carlo_price<T: Value, U: Simulate>(instrument: &T, price_process: &U, annual_discount_rate: f64, number_of_paths: usize, number_of_steps: usize) -> Result<CashFlow, QuantError>
+{
+    check_count("number_of_paths", number_of_paths)?;
+    check_count("number_of_steps", number_of_steps)?;
+    check_finite_input("annual_discount_rate", annual_discount_rate)?;
+    check_expiry(instrument.settlement_datetime())?;
+    price_process.validate()?;
+
+    let payoffs = (0..number_of_paths)
+        .map(|_| instrument.try_calculate_path_payoff(&price_process.generate_path(number_of_steps)))
+        .collect::<Result<Vec<CashFlow>, QuantError>>()?;
+
+    Ok(payoffs.into_iter().sum::<Result<CashFlow, QuantError>>()?
+        .checked_div(number_of_paths as f64)?
+        .value_at_date(Utc::now(), annual_discount_rate))
+}
+
+pub fn try_monte_carlo_price_with_short_rate<T: Value, U: Simulate, V: ShortRateProcess>(instrument: &T, price_process: &U, short_rate_process: &V, number_of_paths: usize, number_of_steps: usize) -> Result<CashFlow, QuantError>
+{
+    check_count("number_of_paths", number_of_paths)?;
+    check_count("number_of_steps", number_of_steps)?;
+    let maturity = check_expiry(instrument.settlement_datetime())?;
+    price_process.validate()?;
+    short_rate_process.validate()?;
+
+    let mut discounted_payoff = 0.0;
+    for _ in 0..number_of_paths {
+        let payoff = instrument.try_calculate_path_payoff(&price_process.generate_path(number_of_steps))?;
+        let short_rate_path = short_rate_process.generate_path(number_of_steps);
+        let discount_factor = path_discount_factor(short_rate_process, &short_rate_path, maturity)
+            .ok_or(QuantError::EmptyInput { name: "numeraire path" })?;
+
+        discounted_payoff += payoff.amount * discount_factor;
+    }
+
+    Ok(CashFlow::new(discounted_payoff / number_of_paths as f64, instrument.underlying_currency(), Utc::now()))
 }
 
 
@@ -22,11 +107,31 @@ mod tests {
 
     use crate::instruments::OptionType;
     use crate::instruments::vanilla_option::VanillaOption;
+    use crate::pricing::black_scholes::black_scholes_price;
     use crate::processes::black_scholes_process::BlackScholesProcess;
     use crate::processes::heston_process::HestonProcess;
+    use crate::processes::rough_bergomi_process::RoughBergomiProcess;
+    use crate::processes::vasicek_process::VasicekProcess;
 
     use super::*;
 
+    #[test]
+    fn test_try_monte_carlo_price_rejects_invalid_inputs() {
+        let option = VanillaOption::new(100.0, Utc::now() + Duration::days(365), Utc::now() + Duration::days(367), OptionType::Call, Currency::USD);
+
+        let invalid_process = BlackScholesProcess::new(100.0, 0.05, -0.2, 1.0);
+        assert!(matches!(try_monte_carlo_price(&option, &invalid_process, 0.05, 100, 10), Err(QuantError::Process(_))));
+
+        let bs_process = BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0);
+        assert_eq!(try_monte_carlo_price(&option, &bs_process, 0.05, 0, 10).unwrap_err(), QuantError::EmptyInput { name: "number_of_paths" });
+        assert!(try_monte_carlo_price(&option, &bs_process, 0.05, 100, 10).is_ok());
+
+        let expired_option = VanillaOption::new(100.0, Utc::now() - Duration::days(3), Utc::now() - Duration::days(1), OptionType::Call, Currency::USD);
+        let short_rate_process = VasicekProcess::new(0.05, 0.5, 0.05, 0.01, 1.0);
+        assert!(matches!(try_monte_carlo_price(&expired_option, &bs_process, 0.05, 100, 10), Err(QuantError::ExpiredInstrument { .. })));
+        assert!(matches!(try_monte_carlo_price_with_short_rate(&expired_option, &bs_process, &short_rate_process, 100, 10), Err(QuantError::ExpiredInstrument { .. })));
+    }
+
     #[test]
     fn test_monte_carlo_black_scholes_vanilla_option() {
         let option = VanillaOption {
@@ -42,6 +147,16 @@ mod tests {
         assert!(price.amount > 0.0, "The calculated option price should be positive.");
     }
 
+    #[test]
+    fn test_monte_carlo_with_continuous_rate_matches_black_scholes() {
+        let option = VanillaOption::new(100.0, Utc::now() + Duration::days(365), Utc::now() + Duration::days(365), OptionType::Call, Currency::USD);
+        let bs_process = BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0);
+
+        let price = monte_carlo_price_with_interest_rate(&option, &bs_process, &InterestRate::continuous(0.05), 20000, 10);
+        let expected_price = black_scholes_price(&option, 100.0, 0.05, 0.2);
+        assert!((price.amount - expected_price.amount).abs() < 0.4, "Price {} too far from Black-Scholes {}", price, expected_price);
+    }
+
     #[test]
     fn test_monte_carlo_heston() {
         let option = VanillaOption {
@@ -95,4 +210,110 @@ mod tests {
         let payoff = monte_carlo_price(&barrier_option, &bs_process, 0.05, 1000, 365);
         assert_almost_eq!(payoff.amount, 0.0, 0.01);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_monte_carlo_vasicek_discounting() {
+        let option = VanillaOption {
+            strike: 100.0,
+            exercise_datetime: Utc::now() + Duration::days(365),
+            settlement_datetime: Utc::now() + Duration::days(365 + 2),
+            option_type: OptionType::Call,
+            underlying_currency: Currency::USD,
+        };
+
+        let bs_process = BlackScholesProcess::new(100.0, 0.05, 0.0, 1.0);
+        let short_rate_process = VasicekProcess::new(0.05, 0.5, 0.05, 0.01, 1.0);
+        let price = monte_carlo_price_with_short_rate(&option, &bs_process, &short_rate_process, 2000, 100);
+
+        // With zero equity volatility the payoff is deterministic, so only the discount factor is random
+        let forward_payoff = 100.0 * (0.05f64 * 1.0 / 100.0 + 1.0).powi(100) - 100.0;
+        let expected_price = forward_payoff * short_rate_process.zero_coupon_bond_price(0.0, time_to_maturity(option.settlement_datetime), 0.05);
+        assert_eq!(price.currency, Currency::USD);
+        assert!((price.amount - expected_price).abs() < 0.02, "Price {} too far from expected {}", price.amount, expected_price);
+    }
+
+    #[test]
+    fn test_monte_carlo_short_rate_discounts_to_settlement() {
+        let (r0, kappa, theta, sigma) = (0.03, 0.5, 0.06, 0.005);
+        let bs_process = BlackScholesProcess::new(100.0, 0.0, 0.0, 1.0);
+
+        // The payoff is a deterministic 100, so the price is the bond price to settlement whatever the process horizon
+        for (horizon, settlement_days) in [(1.0, 182), (1.0, 730), (3.0, 730)] {
+            let option = VanillaOption::new(0.0, Utc::now() + Duration::days(settlement_days), Utc::now() + Duration::days(settlement_days), OptionType::Call, Currency::USD);
+            let short_rate_process = VasicekProcess::new(r0, kappa, theta, sigma, horizon);
+            let expected_price = 100.0 * short_rate_process.zero_coupon_bond_price(0.0, time_to_maturity(option.settlement_datetime), r0);
+
+            let price = try_monte_carlo_price_with_short_rate(&option, &bs_process, &short_rate_process, 2000, 50).unwrap();
+            assert!((price.amount - expected_price).abs() < 0.1, "Horizon {} settlement {}: {} against {}", horizon, settlement_days, price.amount, expected_price);
+        }
+    }
+
+    #[test]
+    fn test_monte_carlo_rough_bergomi_barrier_option() {
+        let barrier_option = BarrierOption {
+            strike: 100.0,
+            exercise_datetime: Utc::now() + Duration::days(365),
+            settlement_datetime: Utc::now() + Duration::days(365 + 2),
+            option_type: OptionType::Put,
+            barrier: Barrier {
+                level: 80.0,
+                barrier_type: BarrierType::DownAndOut,
+            },
+            underlying_currency: Currency::USD,
+        };
+
+        let vanilla_option = VanillaOption::new(100.0, barrier_option.exercise_datetime, barrier_option.settlement_datetime, OptionType::Put, Currency::USD);
+
+        let rb_process = RoughBergomiProcess::new(100.0, 0.05, 0.04, 1.9, 0.1, -0.9, 1.0);
+        let barrier_price = monte_carlo_price(&barrier_option, &rb_process, 0.05, 1000, 100);
+        let vanilla_price = monte_carlo_price(&vanilla_option, &rb_process, 0.05, 1000, 100);
+
+        assert!(barrier_price.amount > 0.0, "The calculated option price should be positive.");
+        assert!(barrier_price.amount < vanilla_price.amount, "A knock-out option should be cheaper than the vanilla option.");
+    }
+
+    // Pays the average simulated variance, which only exists on the variance component of the path
+    struct AverageVariancePayoff {
+        settlement_datetime: chrono::DateTime<Utc>,
+    }
+
+    impl Value for AverageVariancePayoff {
+        fn calculate_payoff(&self, _price_path: &[f64]) -> CashFlow {
+            CashFlow::new(0.0, Currency::USD, self.settlement_datetime)
+        }
+
+        fn calculate_path_payoff(&self, path: &SimulatedPath) -> CashFlow {
+            let variance_path = path.state(StateVariable::Variance).unwrap();
+            let average_variance = variance_path.iter().sum::<f64>() / variance_path.len() as f64;
+            CashFlow::new(average_variance, Currency::USD, self.settlement_datetime)
+        }
+
+        fn settlement_datetime(&self) -> chrono::DateTime<Utc> {
+            self.settlement_datetime
+        }
+
+        fn underlying_currency(&self) -> Currency { Currency::USD }
+    }
+
+    #[test]
+    fn test_monte_carlo_heston_variance_payoff() {
+        let payoff = AverageVariancePayoff { settlement_datetime: Utc::now() };
+
+        // Starting at the long-term variance, the expected variance stays at theta
+        let heston_process = HestonProcess::new(100.0, 0.04, 0.05, 2.0, 0.04, 0.3, -0.7, 1.0);
+        let price = monte_carlo_price(&payoff, &heston_process, 0.0, 2000, 100);
+
+        assert!((price.amount - 0.04).abs() < 0.002, "Average variance {} too far from 0.04", price.amount);
+    }
+
+    #[test]
+    fn test_monte_carlo_prices_in_instrument_currency() {
+        let option = VanillaOption::new(100.0, Utc::now() + Duration::days(365), Utc::now() + Duration::days(365), OptionType::Call, Currency::EUR);
+        let bs_process = BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0);
+        let short_rate_process = VasicekProcess::new(0.05, 0.5, 0.05, 0.01, 1.0);
+
+        assert_eq!(monte_carlo_price(&option, &bs_process, 0.05, 10, 10).currency, Currency::EUR);
+        assert_eq!(monte_carlo_price_with_interest_rate(&option, &bs_process, &InterestRate::continuous(0.05), 10, 10).currency, Currency::EUR);
+        assert_eq!(monte_carlo_price_with_short_rate(&option, &bs_process, &short_rate_process, 10, 10).currency, Currency::EUR);
+    }
+}
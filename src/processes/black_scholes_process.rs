@@ -1,11 +1,15 @@
 extern crate rand;
 extern crate rand_distr;
 
+use chrono::Utc;
 use rand::prelude::*;
 use rand_distr::Normal;
 
+use crate::cashflows::DividendSchedule;
 use crate::processes::Simulate;
+use crate::processes::process_error::{check_finite, check_positive, check_volatility, ProcessError};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
 pub struct BlackScholesProcess {
     pub s0: f64,
     // Initial asset price
@@ -14,30 +18,148 @@ pub struct BlackScholesProcess {
     pub sigma: f64,
     // Volatility of the asset
     pub t: f64,  // Time to maturity
+    pub dividends: DividendSchedule, // Dividends applied as price jumps on their ex-dates
 }
 
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(BlackScholesProcess);
+
 impl BlackScholesProcess {
     pub fn new(s0: f64, r: f64, sigma: f64, t: f64) -> BlackScholesProcess {
-        BlackScholesProcess { s0, r, sigma, t }
+        BlackScholesProcess { s0, r, sigma, t, dividends: DividendSchedule::default() }
+    }
+
+    pub fn try_new(s0: f64, r: f64, sigma: f64, t: f64) -> Result<BlackScholesProcess, ProcessError> {
+        let process = BlackScholesProcess::new(s0, r, sigma, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn with_dividends(self, dividends: DividendSchedule) -> BlackScholesProcess {
+        BlackScholesProcess { dividends, ..self }
     }
 }
 
 impl Simulate for BlackScholesProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_finite("r", self.r)?;
+        check_volatility("sigma", self.sigma)?;
+        check_positive("t", self.t)?;
+        self.dividends.validate()?;
+        Ok(())
+    }
+
     fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
         let mut rng = thread_rng();
         let dt = self.t / number_of_steps as f64;
         let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
         let mut s = self.s0;
 
         let normal = Normal::new(0.0, 1.0).unwrap();
+        let dividends = self.dividends.upcoming(Utc::now());
+        let mut upcoming_dividends = dividends.iter().peekable();
 
-        for _ in 0..number_of_steps {
+        for i in 1..=number_of_steps {
             let dw = normal.sample(&mut rng) * dt.sqrt();
             s += self.r * s * dt + self.sigma * s * dw;
 
+            while let Some((_, dividend_type)) = upcoming_dividends.next_if(|(ex_time, _)| *ex_time <= i as f64 * dt) {
+                s = dividend_type.apply(s);
+            }
+
             s_path.push(s);
         }
 
         s_path
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::cashflows::{Currency, Dividend};
+    use crate::instruments::{OptionType, Value};
+    use crate::instruments::vanilla_option::VanillaOption;
+    use crate::pricing::black_scholes::black_scholes_price_with_dividends;
+    use crate::time::DayCount;
+    use super::*;
+
+    // Mean and standard error of `samples`
+    fn mean_and_error(samples: &[f64]) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (mean, (variance / n).sqrt())
+    }
+
+    #[test]
+    fn test_dividends_lower_mean_terminal_spot() {
+        let ex_datetime = Utc::now() + Duration::days(182);
+        let years_to_ex_date = DayCount::Actual365Fixed.year_fraction(Utc::now(), ex_datetime);
+        let cash = DividendSchedule::new(vec![Dividend::cash(5.0, ex_datetime)]);
+        let proportional = DividendSchedule::new(vec![Dividend::proportional(0.04, ex_datetime)]);
+
+        // The spot drifts at r, so the terminal spot drops by the escrowed cash amount grown to expiry, or by the ratio
+        let forward = 100.0 * 0.05f64.exp();
+        let expected_cash = forward - 5.0 * (0.05 * (1.0 - years_to_ex_date)).exp();
+        for (dividends, expected) in [(cash, expected_cash), (proportional, forward * 0.96)] {
+            let process = BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0).with_dividends(dividends);
+            let terminal_spots: Vec<f64> = (0..10000).map(|_| *process.generate_price_path(50).last().unwrap()).collect();
+            let (mean, error) = mean_and_error(&terminal_spots);
+
+            assert!((mean - expected).abs() < 5.0 * error, "Mean terminal spot {} not within {} of {}", mean, 5.0 * error, expected);
+        }
+    }
+
+    #[test]
+    fn test_monte_carlo_price_with_dividends_matches_black_scholes() {
+        let expiry = Utc::now() + Duration::days(365);
+        let dividends = DividendSchedule::new(vec![
+            Dividend::cash(3.0, Utc::now() + Duration::days(90)),
+            Dividend::proportional(0.02, Utc::now() + Duration::days(270)),
+        ]);
+        let process = BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0).with_dividends(dividends.clone());
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let option = VanillaOption::new(100.0, expiry, expiry, option_type, Currency::USD);
+            let discounted_payoffs: Vec<f64> = (0..10000)
+                .map(|_| option.calculate_payoff(&process.generate_price_path(100)).amount * (-0.05f64).exp())
+                .collect();
+            let (price, error) = mean_and_error(&discounted_payoffs);
+            let expected_price = black_scholes_price_with_dividends(&option, 100.0, 0.05, 0.2, &dividends).amount;
+
+            assert!((price - expected_price).abs() < 5.0 * error, "{:?} price {} not within {} of {}", option_type, price, 5.0 * error, expected_price);
+        }
+    }
+
+    #[test]
+    fn test_invalid_dividends() {
+        let dividends = DividendSchedule::new(vec![Dividend::proportional(1.5, Utc::now() + Duration::days(90))]);
+        let process = BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0).with_dividends(dividends);
+        assert!(matches!(process.validate(), Err(ProcessError::InvalidParameter { name: "dividend ratio", .. })));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(BlackScholesProcess::try_new(100.0, 0.05, -0.2, 1.0).err(), Some(ProcessError::NegativeVolatility { name: "sigma", value: -0.2 }));
+        assert_eq!(BlackScholesProcess::try_new(100.0, 0.05, 0.2, 0.0).err(), Some(ProcessError::NonPositiveParameter { name: "t", value: 0.0 }));
+        assert!(BlackScholesProcess::new(100.0, f64::NAN, 0.2, 1.0).try_generate_path(10).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&BlackScholesProcess::new(100.0, 0.05, 0.2, 1.0)).unwrap();
+        let parsed: BlackScholesProcess = serde_json::from_str(&json).unwrap();
+        assert_eq!((parsed.s0, parsed.sigma), (100.0, 0.2));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+
+        // Deserialization validates the parameters like `try_new`
+        let error = serde_json::from_str::<BlackScholesProcess>(&json.replace(r#""sigma":0.2"#, r#""sigma":-0.2"#)).err().unwrap();
+        assert!(error.to_string().contains("Volatility sigma must not be negative, got -0.2."));
+    }
+}
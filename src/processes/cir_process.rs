@@ -0,0 +1,168 @@
+extern crate rand;
+extern crate rand_distr;
+
+use rand::prelude::*;
+use rand_distr::{ChiSquared, Normal, Poisson};
+
+use crate::processes::{short_rate_simulated_path, ShortRateProcess, Simulate};
+use crate::processes::simulated_path::SimulatedPath;
+use crate::processes::process_error::{check_positive, check_variance, ProcessError};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct CirProcess {
+    pub r0: f64, // Initial short rate
+    pub kappa: f64, // Speed of mean reversion
+    pub theta: f64, // Long-term mean of the short rate
+    pub sigma: f64, // Volatility of the short rate
+    pub t: f64, // Time horizon of the simulation
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(CirProcess);
+
+impl CirProcess {
+    pub fn new(r0: f64, kappa: f64, theta: f64, sigma: f64, t: f64) -> CirProcess {
+        CirProcess { r0, kappa, theta, sigma, t }
+    }
+
+    pub fn try_new(r0: f64, kappa: f64, theta: f64, sigma: f64, t: f64) -> Result<CirProcess, ProcessError> {
+        let process = CirProcess::new(r0, kappa, theta, sigma, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    // Draws from the noncentral chi-squared distribution with `degrees_of_freedom` and `non_centrality`
+    fn sample_noncentral_chi_squared<R: Rng>(rng: &mut R, degrees_of_freedom: f64, non_centrality: f64) -> f64 {
+        if degrees_of_freedom > 1.0 {
+            let z = Normal::new(0.0, 1.0).unwrap().sample(rng);
+            let chi_squared = ChiSquared::new(degrees_of_freedom - 1.0).unwrap().sample(rng);
+            (z + non_centrality.sqrt()).powi(2) + chi_squared
+        } else {
+            let poisson_draw = if non_centrality > 0.0 {
+                Poisson::new(non_centrality / 2.0).unwrap().sample(rng)
+            } else {
+                0.0
+            };
+            ChiSquared::new(degrees_of_freedom + 2.0 * poisson_draw).unwrap().sample(rng)
+        }
+    }
+}
+
+impl Simulate for CirProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_variance("r0", self.r0)?;
+        check_positive("kappa", self.kappa)?;
+        check_positive("theta", self.theta)?;
+        check_positive("sigma", self.sigma)?;
+        check_positive("t", self.t)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut r_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut r = self.r0;
+
+        // The CIR transition density is a scaled noncentral chi-squared distribution
+        let decay = (-self.kappa * dt).exp();
+        let scale = self.sigma.powi(2) * (1.0 - decay) / (4.0 * self.kappa);
+        let degrees_of_freedom = 4.0 * self.kappa * self.theta / self.sigma.powi(2);
+
+        for _ in 0..number_of_steps {
+            let non_centrality = r * decay / scale;
+            r = scale * Self::sample_noncentral_chi_squared(&mut rng, degrees_of_freedom, non_centrality);
+
+            r_path.push(r);
+        }
+
+        r_path
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        short_rate_simulated_path(self, self.generate_price_path(number_of_steps))
+    }
+}
+
+impl ShortRateProcess for CirProcess {
+    fn initial_rate(&self) -> f64 {
+        self.r0
+    }
+
+    fn time_horizon(&self) -> f64 {
+        self.t
+    }
+
+    fn zero_coupon_bond_price(&self, t: f64, maturity: f64, short_rate: f64) -> f64 {
+        let tau = maturity - t;
+        let h = (self.kappa.powi(2) + 2.0 * self.sigma.powi(2)).sqrt();
+        let growth = (h * tau).exp() - 1.0;
+        let denominator = 2.0 * h + (self.kappa + h) * growth;
+
+        let a = (2.0 * h * ((self.kappa + h) * tau / 2.0).exp() / denominator)
+            .powf(2.0 * self.kappa * self.theta / self.sigma.powi(2));
+        let b = 2.0 * growth / denominator;
+
+        a * (-b * short_rate).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Satisfies the Feller condition 2 kappa theta > sigma^2
+    fn create_process() -> CirProcess {
+        CirProcess::new(0.03, 0.5, 0.06, 0.1, 2.0)
+    }
+
+    #[test]
+    fn test_exact_simulation_moments() {
+        let number_of_paths = 20000;
+
+        // Also checks the Poisson mixture branch used when the Feller condition fails
+        for process in [create_process(), CirProcess::new(0.03, 0.5, 0.06, 0.3, 2.0)] {
+            let rates: Vec<f64> = (0..number_of_paths).map(|_| process.generate_price_path(1)[0]).collect();
+            let mean = rates.iter().sum::<f64>() / number_of_paths as f64;
+            let variance = rates.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (number_of_paths - 1) as f64;
+
+            let decay = (-process.kappa * process.t).exp();
+            let expected_mean = process.theta + (process.r0 - process.theta) * decay;
+            let expected_variance = process.r0 * process.sigma.powi(2) / process.kappa * (decay - decay.powi(2))
+                + process.theta * process.sigma.powi(2) / (2.0 * process.kappa) * (1.0 - decay).powi(2);
+            assert!(rates.iter().all(|&r| r >= 0.0));
+            assert!((mean - expected_mean).abs() < 2e-3, "Mean {} too far from {}", mean, expected_mean);
+            assert!((variance / expected_variance - 1.0).abs() < 0.06, "Variance {} too far from {}", variance, expected_variance);
+        }
+    }
+
+    #[test]
+    fn test_simulated_discount_factors_match_bond_price() {
+        let process = create_process();
+        let number_of_paths = 5000;
+
+        let mean_discount_factor = (0..number_of_paths)
+            .map(|_| process.generate_price_path(200))
+            .map(|r_path| *process.discount_factor_path(&r_path).last().unwrap())
+            .sum::<f64>() / number_of_paths as f64;
+
+        let bond_price = process.zero_coupon_bond_price(0.0, process.t, process.r0);
+        assert!((mean_discount_factor - bond_price).abs() < 2e-3, "Mean discount factor {} too far from {}", mean_discount_factor, bond_price);
+    }
+
+    #[test]
+    fn test_bond_price_at_maturity_and_rate_sensitivity() {
+        let process = create_process();
+
+        assert!((process.zero_coupon_bond_price(1.0, 1.0, 0.05) - 1.0).abs() < 1e-15);
+        assert!(process.zero_coupon_bond_price(0.0, 1.0, 0.02) > process.zero_coupon_bond_price(0.0, 1.0, 0.04));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(CirProcess::try_new(-0.01, 0.5, 0.06, 0.1, 2.0).err(), Some(ProcessError::NegativeVariance { name: "r0", value: -0.01 }));
+        assert_eq!(CirProcess::try_new(0.03, 0.5, 0.06, 0.0, 2.0).err(), Some(ProcessError::NonPositiveParameter { name: "sigma", value: 0.0 }));
+    }
+}
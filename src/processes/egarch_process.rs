@@ -0,0 +1,158 @@
+use std::f64::consts::PI;
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::processes::garch_model::{fit_garch_model, log_return_moments, GarchModel, MaximumLikelihoodFit};
+use crate::processes::process_error::{check_finite, check_positive, ProcessError};
+use crate::processes::Simulate;
+use crate::processes::simulated_path::{SimulatedPath, StateVariable};
+
+// EGARCH(1,1) of Nelson: ln h(t+1) = omega + alpha (|z(t)| - E|z|) + gamma z(t) + beta ln h(t) for standardised residuals z(t)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct EgarchProcess {
+    pub s0: f64, // Initial asset price
+    pub mu: f64, // Mean log return per period
+    pub omega: f64, // Constant term of the log variance equation
+    pub alpha: f64, // Reaction to the size of the last standardised residual
+    pub gamma: f64, // Reaction to the sign of the last standardised residual
+    pub beta: f64, // Persistence of the last log variance
+    pub v0: f64, // Conditional variance of the first simulated period
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(EgarchProcess);
+
+impl EgarchProcess {
+    pub fn new(s0: f64, mu: f64, omega: f64, alpha: f64, gamma: f64, beta: f64, v0: f64) -> EgarchProcess {
+        EgarchProcess { s0, mu, omega, alpha, gamma, beta, v0 }
+    }
+
+    pub fn try_new(s0: f64, mu: f64, omega: f64, alpha: f64, gamma: f64, beta: f64, v0: f64) -> Result<EgarchProcess, ProcessError> {
+        let process = EgarchProcess::new(s0, mu, omega, alpha, gamma, beta, v0);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn fit(prices: &[f64]) -> Result<MaximumLikelihoodFit<EgarchProcess>, ProcessError> {
+        let (mean, variance) = log_return_moments(prices)?;
+
+        let beta = 0.9;
+        let initial_guess = EgarchProcess::new(prices[0], mean, (1.0 - beta) * variance.ln(), 0.1, -0.05, beta, variance);
+        fit_garch_model(&initial_guess, prices)
+    }
+
+    fn expected_absolute_shock() -> f64 {
+        (2.0 / PI).sqrt()
+    }
+
+    // E[exp(alpha (|z| - E|z|) + gamma z)] for a standard normal z
+    fn expected_shock_multiplier(&self) -> f64 {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let (up, down) = (self.alpha + self.gamma, self.alpha - self.gamma);
+
+        (-self.alpha * Self::expected_absolute_shock()).exp()
+            * ((0.5 * up.powi(2)).exp() * normal.cdf(up) + (0.5 * down.powi(2)).exp() * normal.cdf(down))
+    }
+}
+
+impl GarchModel for EgarchProcess {
+    fn initial_price(&self) -> f64 {
+        self.s0
+    }
+
+    fn mean_return(&self) -> f64 {
+        self.mu
+    }
+
+    fn initial_variance(&self) -> f64 {
+        self.v0
+    }
+
+    fn next_variance(&self, variance: f64, residual: f64) -> f64 {
+        let z = residual / variance.sqrt();
+        (self.omega + self.alpha * (z.abs() - Self::expected_absolute_shock()) + self.gamma * z + self.beta * variance.ln()).exp()
+    }
+
+    // Conditional variance forecasts, approximating E[h^beta] by E[h]^beta beyond the first step
+    fn forecast_variance(&self, horizon: usize) -> Vec<f64> {
+        let multiplier = self.expected_shock_multiplier();
+        std::iter::successors(Some(self.v0), |variance| Some(self.omega.exp() * variance.powf(self.beta) * multiplier))
+            .take(horizon)
+            .collect()
+    }
+
+    fn is_admissible(&self) -> bool {
+        self.beta.abs() < 1.0
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.mu, self.omega, self.alpha, self.gamma, self.beta]
+    }
+
+    fn parameter_bounds(&self) -> Vec<(f64, f64)> {
+        vec![(-1.0, 1.0), (-50.0, 10.0), (-2.0, 2.0), (-2.0, 2.0), (-0.9999, 0.9999)]
+    }
+
+    fn with_parameters(&self, parameters: &[f64], s0: f64, v0: f64) -> EgarchProcess {
+        EgarchProcess::new(s0, parameters[0], parameters[1], parameters[2], parameters[3], parameters[4], v0)
+    }
+}
+
+impl Simulate for EgarchProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_finite("mu", self.mu)?;
+        check_finite("omega", self.omega)?;
+        check_finite("alpha", self.alpha)?;
+        check_finite("gamma", self.gamma)?;
+        check_finite("beta", self.beta)?;
+        check_positive("v0", self.v0)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.simulate_paths(number_of_steps).0
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        let (s_path, v_path) = self.simulate_paths(number_of_steps);
+        SimulatedPath::from_spot(s_path).with_state(StateVariable::Variance, v_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forecast_matches_simulated_variance() {
+        let process = EgarchProcess::new(100.0, 0.0, -0.5, 0.15, -0.08, 0.95, 2e-4);
+        let number_of_paths = 20000;
+        let horizon = 5;
+
+        let mean_variance = (0..number_of_paths)
+            .map(|_| process.simulate_paths(horizon).1[horizon - 1])
+            .sum::<f64>() / number_of_paths as f64;
+
+        let forecast = process.forecast_variance(horizon);
+        assert_eq!(forecast[0], 2e-4);
+        assert!((forecast[horizon - 1] / mean_variance - 1.0).abs() < 0.05, "Forecast {} too far from simulated {}", forecast[horizon - 1], mean_variance);
+    }
+
+    #[test]
+    fn test_fit_captures_leverage() {
+        let true_process = EgarchProcess::new(100.0, 0.0, -0.4, 0.15, -0.1, 0.95, 1e-4);
+        let prices: Vec<f64> = std::iter::once(100.0).chain(true_process.generate_price_path(4000)).collect();
+
+        let fit = EgarchProcess::fit(&prices).unwrap();
+
+        assert!(fit.model.gamma < 0.0, "Fitted gamma {}", fit.model.gamma);
+        assert!((fit.model.beta - 0.95).abs() < 0.05, "Fitted beta {}", fit.model.beta);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(EgarchProcess::try_new(100.0, 0.0, -0.5, 0.15, -0.08, 0.95, 0.0).err(), Some(ProcessError::NonPositiveParameter { name: "v0", value: 0.0 }));
+        assert!(matches!(EgarchProcess::try_new(100.0, 0.0, f64::INFINITY, 0.15, -0.08, 0.95, 2e-4), Err(ProcessError::NonFiniteParameter { name: "omega", .. })));
+    }
+}
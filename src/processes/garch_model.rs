@@ -0,0 +1,140 @@
+extern crate rand;
+extern crate rand_distr;
+
+use std::f64::consts::PI;
+
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::math::optimization::{nelder_mead, NelderMeadSettings};
+use crate::processes::process_error::ProcessError;
+use crate::processes::Simulate;
+
+const MINIMUM_OBSERVATIONS: usize = 10;
+
+pub struct MaximumLikelihoodFit<M> {
+    pub model: M, // Fitted model, conditioned on the sample so that it simulates and forecasts from its last observation
+    pub log_likelihood: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+// Discrete-time conditional variance models driven by Gaussian innovations, one step per observation period
+pub trait GarchModel: Simulate + Sized {
+    fn initial_price(&self) -> f64;
+
+    fn mean_return(&self) -> f64;
+
+    fn initial_variance(&self) -> f64;
+
+    // Conditional variance of the next period given the current variance and return residual
+    fn next_variance(&self, variance: f64, residual: f64) -> f64;
+
+    fn forecast_variance(&self, horizon: usize) -> Vec<f64>;
+
+    // Positive variances and a stationary variance process
+    fn is_admissible(&self) -> bool;
+
+    // Mean return followed by the variance equation parameters
+    fn parameters(&self) -> Vec<f64>;
+
+    fn parameter_bounds(&self) -> Vec<(f64, f64)>;
+
+    fn with_parameters(&self, parameters: &[f64], s0: f64, v0: f64) -> Self;
+
+    fn simulate_paths(&self, number_of_steps: usize) -> (Vec<f64>, Vec<f64>) {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut v_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut s = self.initial_price();
+        let mut v = self.initial_variance();
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for _ in 0..number_of_steps {
+            let residual = v.sqrt() * normal.sample(&mut rng);
+            s *= (self.mean_return() + residual).exp();
+
+            s_path.push(s);
+            v_path.push(v);
+
+            v = self.next_variance(v, residual);
+        }
+
+        (s_path, v_path)
+    }
+
+    // Conditional variances of each log return, followed by the forecast for the period after the sample
+    fn conditional_variances(&self, log_returns: &[f64]) -> Vec<f64> {
+        let mut variances = Vec::with_capacity(log_returns.len() + 1);
+        let mut v = self.initial_variance();
+        variances.push(v);
+
+        for log_return in log_returns {
+            v = self.next_variance(v, log_return - self.mean_return());
+            variances.push(v);
+        }
+
+        variances
+    }
+
+    fn log_likelihood(&self, log_returns: &[f64]) -> f64 {
+        self.conditional_variances(log_returns).iter()
+            .zip(log_returns.iter())
+            .map(|(variance, log_return)| -0.5 * ((2.0 * PI).ln() + variance.ln() + (log_return - self.mean_return()).powi(2) / variance))
+            .sum()
+    }
+}
+
+pub fn log_returns(prices: &[f64]) -> Result<Vec<f64>, ProcessError> {
+    if prices.len() < MINIMUM_OBSERVATIONS + 1 {
+        return Err(ProcessError::InsufficientData { required: MINIMUM_OBSERVATIONS + 1, provided: prices.len() });
+    }
+    if let Some(&price) = prices.iter().find(|price| !(price.is_finite() && **price > 0.0)) {
+        return Err(ProcessError::InvalidParameter { name: "prices", value: price, reason: "historical prices must be positive and finite" });
+    }
+
+    Ok(prices.windows(2).map(|window| (window[1] / window[0]).ln()).collect())
+}
+
+fn moments(log_returns: &[f64]) -> (f64, f64) {
+    let mean = log_returns.iter().sum::<f64>() / log_returns.len() as f64;
+    let variance = log_returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / log_returns.len() as f64;
+    (mean, variance)
+}
+
+// Mean and variance of the log returns, from which the fits derive their initial guesses
+pub(crate) fn log_return_moments(prices: &[f64]) -> Result<(f64, f64), ProcessError> {
+    Ok(moments(&log_returns(prices)?))
+}
+
+// Gaussian maximum likelihood estimation started from `initial_guess`, with the variance recursion initialised at the
+// sample variance of the log returns
+pub fn fit_garch_model<M: GarchModel>(initial_guess: &M, prices: &[f64]) -> Result<MaximumLikelihoodFit<M>, ProcessError> {
+    let log_returns = log_returns(prices)?;
+    let (_, sample_variance) = moments(&log_returns);
+    let last_price = prices[prices.len() - 1];
+
+    let negative_log_likelihood = |parameters: &[f64]| {
+        let model = initial_guess.with_parameters(parameters, last_price, sample_variance);
+        if !model.is_admissible() {
+            return f64::INFINITY;
+        }
+        -model.log_likelihood(&log_returns)
+    };
+
+    let settings = NelderMeadSettings { max_iterations: 20000, tolerance: 1e-12, ..NelderMeadSettings::default() };
+    let result = nelder_mead(negative_log_likelihood, &initial_guess.parameters(), &initial_guess.parameter_bounds(), settings);
+
+    let sample_model = initial_guess.with_parameters(&result.parameters, last_price, sample_variance);
+    let next_variance = *sample_model.conditional_variances(&log_returns).last().unwrap();
+
+    Ok(MaximumLikelihoodFit {
+        model: initial_guess.with_parameters(&result.parameters, last_price, next_variance),
+        log_likelihood: -result.cost,
+        iterations: result.iterations,
+        converged: result.converged,
+    })
+}
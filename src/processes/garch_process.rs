@@ -0,0 +1,158 @@
+use crate::processes::garch_model::{fit_garch_model, log_return_moments, GarchModel, MaximumLikelihoodFit};
+use crate::processes::process_error::{check_finite, check_non_negative, check_positive, check_variance, ProcessError};
+use crate::processes::Simulate;
+use crate::processes::simulated_path::{SimulatedPath, StateVariable};
+
+// GARCH(1,1): h(t+1) = omega + alpha e(t)^2 + beta h(t) for the log return residuals e(t)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct GarchProcess {
+    pub s0: f64, // Initial asset price
+    pub mu: f64, // Mean log return per period
+    pub omega: f64, // Constant term of the variance equation
+    pub alpha: f64, // Reaction to the last squared residual
+    pub beta: f64, // Persistence of the last variance
+    pub v0: f64, // Conditional variance of the first simulated period
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(GarchProcess);
+
+impl GarchProcess {
+    pub fn new(s0: f64, mu: f64, omega: f64, alpha: f64, beta: f64, v0: f64) -> GarchProcess {
+        GarchProcess { s0, mu, omega, alpha, beta, v0 }
+    }
+
+    pub fn try_new(s0: f64, mu: f64, omega: f64, alpha: f64, beta: f64, v0: f64) -> Result<GarchProcess, ProcessError> {
+        let process = GarchProcess::new(s0, mu, omega, alpha, beta, v0);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn fit(prices: &[f64]) -> Result<MaximumLikelihoodFit<GarchProcess>, ProcessError> {
+        let (mean, variance) = log_return_moments(prices)?;
+
+        let initial_guess = GarchProcess::new(prices[0], mean, 0.05 * variance, 0.05, 0.9, variance);
+        fit_garch_model(&initial_guess, prices)
+    }
+
+    pub fn unconditional_variance(&self) -> Option<f64> {
+        let persistence = self.alpha + self.beta;
+        if persistence < 1.0 { Some(self.omega / (1.0 - persistence)) } else { None }
+    }
+}
+
+impl GarchModel for GarchProcess {
+    fn initial_price(&self) -> f64 {
+        self.s0
+    }
+
+    fn mean_return(&self) -> f64 {
+        self.mu
+    }
+
+    fn initial_variance(&self) -> f64 {
+        self.v0
+    }
+
+    fn next_variance(&self, variance: f64, residual: f64) -> f64 {
+        self.omega + self.alpha * residual.powi(2) + self.beta * variance
+    }
+
+    // Expected conditional variances for the next `horizon` periods
+    fn forecast_variance(&self, horizon: usize) -> Vec<f64> {
+        std::iter::successors(Some(self.v0), |variance| Some(self.omega + (self.alpha + self.beta) * variance))
+            .take(horizon)
+            .collect()
+    }
+
+    fn is_admissible(&self) -> bool {
+        self.omega > 0.0 && self.alpha >= 0.0 && self.beta >= 0.0 && self.alpha + self.beta < 1.0
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.mu, self.omega, self.alpha, self.beta]
+    }
+
+    fn parameter_bounds(&self) -> Vec<(f64, f64)> {
+        vec![(-1.0, 1.0), (1e-12, 1.0), (0.0, 1.0), (0.0, 1.0)]
+    }
+
+    fn with_parameters(&self, parameters: &[f64], s0: f64, v0: f64) -> GarchProcess {
+        GarchProcess::new(s0, parameters[0], parameters[1], parameters[2], parameters[3], v0)
+    }
+}
+
+impl Simulate for GarchProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_finite("mu", self.mu)?;
+        check_positive("omega", self.omega)?;
+        check_non_negative("alpha", self.alpha)?;
+        check_non_negative("beta", self.beta)?;
+        check_variance("v0", self.v0)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.simulate_paths(number_of_steps).0
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        let (s_path, v_path) = self.simulate_paths(number_of_steps);
+        SimulatedPath::from_spot(s_path).with_state(StateVariable::Variance, v_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::processes::garch_model::log_returns;
+
+    use super::*;
+
+    #[test]
+    fn test_forecast_converges_to_unconditional_variance() {
+        let process = GarchProcess::new(100.0, 0.0, 2e-6, 0.08, 0.9, 4e-4);
+        let forecast = process.forecast_variance(2000);
+
+        assert_eq!(forecast[0], 4e-4);
+        assert!((forecast[1999] - process.unconditional_variance().unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fit_recovers_parameters() {
+        let true_process = GarchProcess::new(100.0, 2e-4, 5e-6, 0.1, 0.85, 1e-4);
+        let prices: Vec<f64> = std::iter::once(100.0).chain(true_process.generate_price_path(5000)).collect();
+
+        let fit = GarchProcess::fit(&prices).unwrap();
+
+        assert!(fit.converged);
+        assert!(fit.log_likelihood >= true_process.with_parameters(&true_process.parameters(), 100.0, 1e-4).log_likelihood(&log_returns(&prices).unwrap()) - 5.0);
+        assert!((fit.model.alpha - 0.1).abs() < 0.05, "Fitted alpha {}", fit.model.alpha);
+        assert!((fit.model.beta - 0.85).abs() < 0.07, "Fitted beta {}", fit.model.beta);
+        assert_eq!(fit.model.s0, prices[prices.len() - 1]);
+    }
+
+    #[test]
+    fn test_fit_requires_enough_prices() {
+        let result = GarchProcess::fit(&[100.0, 101.0, 99.0]);
+        assert_eq!(result.err(), Some(ProcessError::InsufficientData { required: 11, provided: 3 }));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(GarchProcess::try_new(100.0, 0.0, 2e-6, -0.08, 0.9, 4e-4).err(), Some(ProcessError::NegativeParameter { name: "alpha", value: -0.08 }));
+        assert_eq!(GarchProcess::try_new(100.0, 0.0, 0.0, 0.08, 0.9, 4e-4).err(), Some(ProcessError::NonPositiveParameter { name: "omega", value: 0.0 }));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&GarchProcess::new(100.0, 0.0, 2e-6, 0.08, 0.9, 4e-4)).unwrap();
+        let parsed: GarchProcess = serde_json::from_str(&json).unwrap();
+        assert_eq!((parsed.omega, parsed.alpha, parsed.beta), (2e-6, 0.08, 0.9));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+
+        let error = serde_json::from_str::<GarchProcess>(&json.replace(r#""alpha":0.08"#, r#""alpha":-0.08"#)).err().unwrap();
+        assert!(error.to_string().contains("Parameter alpha must not be negative, got -0.08."));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::processes::garch_model::{fit_garch_model, log_return_moments, GarchModel, MaximumLikelihoodFit};
+use crate::processes::process_error::{check_finite, check_non_negative, check_positive, check_variance, ProcessError};
+use crate::processes::Simulate;
+use crate::processes::simulated_path::{SimulatedPath, StateVariable};
+
+// GJR-GARCH(1,1): negative residuals add gamma e(t)^2 on top of the GARCH(1,1) reaction, capturing the leverage effect
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct GjrGarchProcess {
+    pub s0: f64, // Initial asset price
+    pub mu: f64, // Mean log return per period
+    pub omega: f64, // Constant term of the variance equation
+    pub alpha: f64, // Reaction to the last squared residual
+    pub gamma: f64, // Additional reaction to negative residuals
+    pub beta: f64, // Persistence of the last variance
+    pub v0: f64, // Conditional variance of the first simulated period
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(GjrGarchProcess);
+
+impl GjrGarchProcess {
+    pub fn new(s0: f64, mu: f64, omega: f64, alpha: f64, gamma: f64, beta: f64, v0: f64) -> GjrGarchProcess {
+        GjrGarchProcess { s0, mu, omega, alpha, gamma, beta, v0 }
+    }
+
+    pub fn try_new(s0: f64, mu: f64, omega: f64, alpha: f64, gamma: f64, beta: f64, v0: f64) -> Result<GjrGarchProcess, ProcessError> {
+        let process = GjrGarchProcess::new(s0, mu, omega, alpha, gamma, beta, v0);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn fit(prices: &[f64]) -> Result<MaximumLikelihoodFit<GjrGarchProcess>, ProcessError> {
+        let (mean, variance) = log_return_moments(prices)?;
+
+        let initial_guess = GjrGarchProcess::new(prices[0], mean, 0.05 * variance, 0.03, 0.05, 0.9, variance);
+        fit_garch_model(&initial_guess, prices)
+    }
+
+    // With symmetric innovations, negative residuals occur half of the time
+    fn persistence(&self) -> f64 {
+        self.alpha + 0.5 * self.gamma + self.beta
+    }
+
+    pub fn unconditional_variance(&self) -> Option<f64> {
+        if self.persistence() < 1.0 { Some(self.omega / (1.0 - self.persistence())) } else { None }
+    }
+}
+
+impl GarchModel for GjrGarchProcess {
+    fn initial_price(&self) -> f64 {
+        self.s0
+    }
+
+    fn mean_return(&self) -> f64 {
+        self.mu
+    }
+
+    fn initial_variance(&self) -> f64 {
+        self.v0
+    }
+
+    fn next_variance(&self, variance: f64, residual: f64) -> f64 {
+        let leverage = if residual < 0.0 { self.gamma } else { 0.0 };
+        self.omega + (self.alpha + leverage) * residual.powi(2) + self.beta * variance
+    }
+
+    // Expected conditional variances for the next `horizon` periods
+    fn forecast_variance(&self, horizon: usize) -> Vec<f64> {
+        std::iter::successors(Some(self.v0), |variance| Some(self.omega + self.persistence() * variance))
+            .take(horizon)
+            .collect()
+    }
+
+    fn is_admissible(&self) -> bool {
+        self.omega > 0.0 && self.alpha >= 0.0 && self.alpha + self.gamma >= 0.0 && self.beta >= 0.0 && self.persistence() < 1.0
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        vec![self.mu, self.omega, self.alpha, self.gamma, self.beta]
+    }
+
+    fn parameter_bounds(&self) -> Vec<(f64, f64)> {
+        vec![(-1.0, 1.0), (1e-12, 1.0), (0.0, 1.0), (-1.0, 1.0), (0.0, 1.0)]
+    }
+
+    fn with_parameters(&self, parameters: &[f64], s0: f64, v0: f64) -> GjrGarchProcess {
+        GjrGarchProcess::new(s0, parameters[0], parameters[1], parameters[2], parameters[3], parameters[4], v0)
+    }
+}
+
+impl Simulate for GjrGarchProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_finite("mu", self.mu)?;
+        check_positive("omega", self.omega)?;
+        check_non_negative("alpha", self.alpha)?;
+        check_finite("gamma", self.gamma)?;
+        check_non_negative("alpha + gamma", self.alpha + self.gamma)?;
+        check_non_negative("beta", self.beta)?;
+        check_variance("v0", self.v0)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.simulate_paths(number_of_steps).0
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        let (s_path, v_path) = self.simulate_paths(number_of_steps);
+        SimulatedPath::from_spot(s_path).with_state(StateVariable::Variance, v_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forecast_converges_to_unconditional_variance() {
+        let process = GjrGarchProcess::new(100.0, 0.0, 2e-6, 0.05, 0.08, 0.88, 4e-4);
+        let forecast = process.forecast_variance(3000);
+
+        assert_eq!(forecast[0], 4e-4);
+        assert!((forecast[2999] - process.unconditional_variance().unwrap()).abs() < 1e-12);
+        assert_eq!(GjrGarchProcess::new(100.0, 0.0, 2e-6, 0.1, 0.2, 0.85, 4e-4).unconditional_variance(), None);
+    }
+
+    #[test]
+    fn test_forecast_matches_simulated_variance() {
+        let process = GjrGarchProcess::new(100.0, 0.0, 2e-5, 0.05, 0.15, 0.8, 2e-4);
+        let number_of_paths = 20000;
+        let horizon = 5;
+
+        let mean_variance = (0..number_of_paths)
+            .map(|_| process.simulate_paths(horizon).1[horizon - 1])
+            .sum::<f64>() / number_of_paths as f64;
+
+        let forecast = process.forecast_variance(horizon);
+        assert!((forecast[horizon - 1] / mean_variance - 1.0).abs() < 0.05, "Forecast {} too far from simulated {}", forecast[horizon - 1], mean_variance);
+    }
+
+    #[test]
+    fn test_fit_captures_leverage() {
+        let true_process = GjrGarchProcess::new(100.0, 2e-4, 5e-6, 0.03, 0.12, 0.88, 1e-4);
+        let prices: Vec<f64> = std::iter::once(100.0).chain(true_process.generate_price_path(5000)).collect();
+
+        let fit = GjrGarchProcess::fit(&prices).unwrap();
+
+        assert!(fit.converged);
+        assert!(fit.model.gamma > 0.0, "Fitted gamma {}", fit.model.gamma);
+        assert!((fit.model.beta - 0.88).abs() < 0.07, "Fitted beta {}", fit.model.beta);
+        assert!(fit.model.is_admissible());
+        assert_eq!(fit.model.s0, prices[prices.len() - 1]);
+    }
+
+    #[test]
+    fn test_fit_rejects_invalid_prices() {
+        let mut prices = vec![100.0; 20];
+        prices[5] = -1.0;
+        assert!(matches!(GjrGarchProcess::fit(&prices), Err(ProcessError::InvalidParameter { name: "prices", .. })));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        // A negative leverage term is allowed as long as negative shocks do not lower the variance
+        assert!(GjrGarchProcess::try_new(100.0, 0.0, 2e-6, 0.08, -0.05, 0.9, 4e-4).is_ok());
+        assert!(matches!(GjrGarchProcess::try_new(100.0, 0.0, 2e-6, 0.08, -0.1, 0.9, 4e-4), Err(ProcessError::NegativeParameter { name: "alpha + gamma", .. })));
+        assert_eq!(GjrGarchProcess::try_new(100.0, 0.0, 2e-6, 0.08, 0.1, 0.9, -4e-4).err(), Some(ProcessError::NegativeVariance { name: "v0", value: -4e-4 }));
+    }
+}
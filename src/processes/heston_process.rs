@@ -1,11 +1,17 @@
 extern crate rand;
 extern crate rand_distr;
 
+use chrono::Utc;
+use num_complex::Complex;
 use rand::prelude::*;
 use rand_distr::Normal;
 
-use crate::processes::Simulate;
+use crate::cashflows::DividendSchedule;
+use crate::processes::{CharacteristicFunction, Simulate};
+use crate::processes::simulated_path::{SimulatedPath, StateVariable};
+use crate::processes::process_error::{check_correlation, check_finite, check_positive, check_variance, check_volatility, ProcessError, ProcessWarning};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
 pub struct HestonProcess {
     pub s0: f64,
     // Initial asset price
@@ -22,26 +28,58 @@ pub struct HestonProcess {
     pub rho: f64,
     // Correlation between the two Brownian motions
     pub t: f64, // Time to maturity
+    pub dividends: DividendSchedule, // Dividends applied as price jumps on their ex-dates
 }
 
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(HestonProcess);
+
 impl HestonProcess {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(s0: f64, v0: f64, r: f64, kappa: f64, theta: f64, sigma: f64, rho: f64, t: f64) -> HestonProcess {
-        HestonProcess { s0, v0, r, kappa, theta, sigma, rho, t }
+        HestonProcess { s0, v0, r, kappa, theta, sigma, rho, t, dividends: DividendSchedule::default() }
     }
-}
 
-impl Simulate for HestonProcess
-{
-    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(s0: f64, v0: f64, r: f64, kappa: f64, theta: f64, sigma: f64, rho: f64, t: f64) -> Result<HestonProcess, ProcessError> {
+        let process = HestonProcess::new(s0, v0, r, kappa, theta, sigma, rho, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn with_dividends(self, dividends: DividendSchedule) -> HestonProcess {
+        HestonProcess { dividends, ..self }
+    }
+
+    pub fn feller_condition_satisfied(&self) -> bool {
+        2.0 * self.kappa * self.theta >= self.sigma.powi(2)
+    }
+
+    // Conditions that do not invalidate the parameters but degrade the simulation
+    pub fn warnings(&self) -> Vec<ProcessWarning> {
+        let mut warnings = Vec::new();
+        if !self.feller_condition_satisfied() {
+            warnings.push(ProcessWarning::FellerConditionViolated { two_kappa_theta: 2.0 * self.kappa * self.theta, sigma_squared: self.sigma.powi(2) });
+        }
+        warnings
+    }
+
+    // Returns the spot and variance paths, sampled at the end of each step
+    fn generate_paths(&self, number_of_steps: usize) -> (Vec<f64>, Vec<f64>) {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
         let mut rng = thread_rng();
         let dt = self.t / number_of_steps as f64;
         let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut v_path: Vec<f64> = Vec::with_capacity(number_of_steps);
         let mut s = self.s0;
         let mut v = self.v0;
 
         let normal = Normal::new(0.0, 1.0).unwrap();
+        let dividends = self.dividends.upcoming(Utc::now());
+        let mut upcoming_dividends = dividends.iter().peekable();
 
-        for _ in 0..number_of_steps {
+        for i in 1..=number_of_steps {
             let dw_s = normal.sample(&mut rng) * dt.sqrt();
             let dw_v = self.rho * dw_s + (1.0 - self.rho.powi(2)).sqrt() * normal.sample(&mut rng) * dt.sqrt();
 
@@ -50,9 +88,185 @@ impl Simulate for HestonProcess
 
             v = v.max(0.0);
 
+            while let Some((_, dividend_type)) = upcoming_dividends.next_if(|(ex_time, _)| *ex_time <= i as f64 * dt) {
+                s = dividend_type.apply(s);
+            }
+
             s_path.push(s);
+            v_path.push(v);
+        }
+
+        (s_path, v_path)
+    }
+}
+
+impl Simulate for HestonProcess
+{
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_variance("v0", self.v0)?;
+        check_finite("r", self.r)?;
+        check_positive("kappa", self.kappa)?;
+        check_variance("theta", self.theta)?;
+        check_volatility("sigma", self.sigma)?;
+        check_correlation("rho", self.rho)?;
+        check_positive("t", self.t)?;
+        self.dividends.validate()?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.generate_paths(number_of_steps).0
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        let (s_path, v_path) = self.generate_paths(number_of_steps);
+        SimulatedPath::from_spot(s_path).with_state(StateVariable::Variance, v_path)
+    }
+}
+
+impl CharacteristicFunction for HestonProcess {
+    // Albrecher et al. formulation, which avoids the branch cut discontinuity of the original Heston formula. Dividends
+    // going ex before `t` are taken out of the spot as in the escrowed dividend Black-Scholes model.
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let escrowed_s0 = self.dividends.escrowed_spot_between(self.s0, self.r, 0.0, t, &self.dividends.upcoming(Utc::now()));
+        let i: Complex<f64> = Complex::i();
+        let sigma_squared = self.sigma.powi(2);
+
+        let xi = self.kappa - self.sigma * self.rho * i * u;
+        let d = (xi * xi + sigma_squared * (u * u + i * u)).sqrt();
+        let g = (xi - d) / (xi + d);
+        let decay = (-d * t).exp();
+
+        let c = self.r * i * u * t
+            + self.kappa * self.theta / sigma_squared * ((xi - d) * t - 2.0 * ((1.0 - g * decay) / (1.0 - g)).ln());
+        let d_term = (xi - d) / sigma_squared * (1.0 - decay) / (1.0 - g * decay);
+
+        (i * u * escrowed_s0.ln() + c + d_term * self.v0).exp()
+    }
+
+    fn initial_price(&self) -> f64 {
+        self.s0
+    }
+
+    fn risk_free_rate(&self) -> f64 {
+        self.r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::cashflows::{Currency, Dividend};
+    use crate::instruments::{OptionType, Value};
+    use crate::instruments::vanilla_option::VanillaOption;
+    use crate::pricing::black_scholes::black_scholes_price_with_dividends;
+    use super::*;
+
+    // Mean and standard error of `samples`
+    fn mean_and_error(samples: &[f64]) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (mean, (variance / n).sqrt())
+    }
+
+    #[test]
+    fn test_dividends_lower_mean_terminal_spot() {
+        let ex_datetime = Utc::now() + Duration::days(182);
+        let years_to_ex_date = crate::time::DayCount::Actual365Fixed.year_fraction(Utc::now(), ex_datetime);
+        let cash = DividendSchedule::new(vec![Dividend::cash(5.0, ex_datetime)]);
+        let proportional = DividendSchedule::new(vec![Dividend::proportional(0.04, ex_datetime)]);
+
+        let forward = 100.0 * 0.05f64.exp();
+        let expected_cash = forward - 5.0 * (0.05 * (1.0 - years_to_ex_date)).exp();
+        for (dividends, expected) in [(cash, expected_cash), (proportional, forward * 0.96)] {
+            let process = HestonProcess::new(100.0, 0.04, 0.05, 2.0, 0.04, 0.3, -0.7, 1.0).with_dividends(dividends);
+            let terminal_spots: Vec<f64> = (0..10000).map(|_| *process.generate_price_path(50).last().unwrap()).collect();
+            let (mean, error) = mean_and_error(&terminal_spots);
+
+            assert!((mean - expected).abs() < 5.0 * error, "Mean terminal spot {} not within {} of {}", mean, 5.0 * error, expected);
+        }
+    }
+
+    #[test]
+    fn test_monte_carlo_price_with_dividends_matches_black_scholes() {
+        // With a constant variance the Heston model reduces to Black-Scholes
+        let expiry = Utc::now() + Duration::days(365);
+        let dividends = DividendSchedule::new(vec![
+            Dividend::cash(3.0, Utc::now() + Duration::days(90)),
+            Dividend::proportional(0.02, Utc::now() + Duration::days(270)),
+        ]);
+        let process = HestonProcess::new(100.0, 0.04, 0.05, 2.0, 0.04, 1e-4, 0.0, 1.0).with_dividends(dividends.clone());
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let option = VanillaOption::new(100.0, expiry, expiry, option_type, Currency::USD);
+            let discounted_payoffs: Vec<f64> = (0..10000)
+                .map(|_| option.calculate_payoff(&process.generate_price_path(100)).amount * (-0.05f64).exp())
+                .collect();
+            let (price, error) = mean_and_error(&discounted_payoffs);
+            let expected_price = black_scholes_price_with_dividends(&option, 100.0, 0.05, 0.2, &dividends).amount;
+
+            assert!((price - expected_price).abs() < 5.0 * error, "{:?} price {} not within {} of {}", option_type, price, 5.0 * error, expected_price);
         }
+    }
+
+    #[test]
+    fn test_invalid_dividends() {
+        let dividends = DividendSchedule::new(vec![Dividend::cash(-2.0, Utc::now() + Duration::days(90))]);
+        let process = HestonProcess::new(100.0, 0.04, 0.05, 2.0, 0.04, 0.3, -0.7, 1.0).with_dividends(dividends);
+        assert_eq!(process.validate(), Err(ProcessError::NegativeParameter { name: "dividend amount", value: -2.0 }));
+    }
+
+    #[test]
+    fn test_valid_parameters() {
+        let process = HestonProcess::try_new(100.0, 0.04, 0.05, 2.0, 0.04, 0.3, -0.7, 1.0).unwrap();
+        assert!(process.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_correlation_out_of_range() {
+        let error = HestonProcess::try_new(100.0, 0.04, 0.05, 2.0, 0.04, 0.3, -1.5, 1.0).err();
+        assert_eq!(error, Some(ProcessError::CorrelationOutOfRange { name: "rho", value: -1.5 }));
+    }
+
+    #[test]
+    fn test_negative_variance() {
+        let error = HestonProcess::try_new(100.0, -0.04, 0.05, 2.0, 0.04, 0.3, -0.7, 1.0).err();
+        assert_eq!(error, Some(ProcessError::NegativeVariance { name: "v0", value: -0.04 }));
+    }
+
+    #[test]
+    fn test_feller_condition_warning() {
+        let process = HestonProcess::try_new(100.0, 0.04, 0.05, 0.5, 0.04, 0.8, -0.7, 1.0).unwrap();
+        assert_eq!(process.warnings(), vec![ProcessWarning::FellerConditionViolated { two_kappa_theta: 0.04, sigma_squared: 0.6400000000000001 }]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Volatility sigma must not be negative, got -0.3.")]
+    fn test_simulation_refuses_invalid_parameters() {
+        let process = HestonProcess::new(100.0, 0.04, 0.05, 2.0, 0.04, -0.3, -0.7, 1.0);
+        let _ = process.generate_price_path(10);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use chrono::TimeZone;
+
+        let dividends = DividendSchedule::new(vec![crate::cashflows::Dividend::cash(2.0, Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap())]);
+        let process = HestonProcess::new(100.0, 0.04, 0.05, 2.0, 0.04, 0.3, -0.7, 1.0).with_dividends(dividends);
+        let json = serde_json::to_string(&process).unwrap();
+
+        let parsed: HestonProcess = serde_json::from_str(&json).unwrap();
+        assert_eq!((parsed.kappa, parsed.rho), (2.0, -0.7));
+        assert_eq!(parsed.dividends, process.dividends);
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
 
-        s_path
+        // Deserialization validates the parameters like `try_new`
+        let error = serde_json::from_str::<HestonProcess>(&json.replace(r#""sigma":0.3"#, r#""sigma":-0.3"#)).err().unwrap();
+        assert!(error.to_string().contains("Volatility sigma must not be negative, got -0.3."));
+        assert!(serde_json::from_str::<HestonProcess>(&json.replace(r#""rho":-0.7"#, r#""rho":-1.7"#)).is_err());
     }
 }
@@ -0,0 +1,206 @@
+extern crate rand;
+extern crate rand_distr;
+
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::processes::{short_rate_simulated_path, ShortRateProcess, Simulate};
+use crate::processes::simulated_path::SimulatedPath;
+use crate::processes::process_error::{check_finite, check_positive, check_volatility, ProcessError};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct HullWhiteProcess {
+    pub kappa: f64, // Speed of mean reversion
+    pub sigma: f64, // Volatility of the short rate
+    pub discount_curve: Vec<(f64, f64)>, // Initial discount curve as (time, discount factor) pillars
+    pub t: f64, // Time horizon of the simulation
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(HullWhiteProcess);
+
+impl HullWhiteProcess {
+    pub fn new(kappa: f64, sigma: f64, discount_curve: Vec<(f64, f64)>, t: f64) -> HullWhiteProcess {
+        let mut discount_curve = discount_curve;
+        discount_curve.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        HullWhiteProcess { kappa, sigma, discount_curve, t }
+    }
+
+    pub fn try_new(kappa: f64, sigma: f64, discount_curve: Vec<(f64, f64)>, t: f64) -> Result<HullWhiteProcess, ProcessError> {
+        let process = HullWhiteProcess::new(kappa, sigma, discount_curve, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    // Returns the pillars bracketing `t`, with an implicit (0, 1) pillar at the origin and flat forward extrapolation
+    fn segment(&self, t: f64) -> ((f64, f64), (f64, f64)) {
+        let pillars: Vec<(f64, f64)> = std::iter::once((0.0, 1.0))
+            .chain(self.discount_curve.iter().copied().filter(|pillar| pillar.0 > 0.0))
+            .collect();
+        if pillars.len() == 1 {
+            return (pillars[0], pillars[0]);
+        }
+
+        let i = pillars.iter().position(|pillar| t < pillar.0).unwrap_or(pillars.len() - 1).max(1);
+        (pillars[i - 1], pillars[i])
+    }
+
+    pub fn initial_forward_rate(&self, t: f64) -> f64 {
+        let ((t1, p1), (t2, p2)) = self.segment(t);
+        if t2 <= t1 {
+            return 0.0;
+        }
+        -(p2.ln() - p1.ln()) / (t2 - t1)
+    }
+
+    pub fn initial_discount_factor(&self, t: f64) -> f64 {
+        let ((t1, p1), _) = self.segment(t);
+        p1 * (-self.initial_forward_rate(t) * (t - t1)).exp()
+    }
+
+    // Time-dependent drift that makes the model reprice the initial discount curve
+    pub fn theta(&self, t: f64) -> f64 {
+        let h = 1e-4;
+        let forward_slope = (self.initial_forward_rate(t + h) - self.initial_forward_rate((t - h).max(0.0))) / (t + h - (t - h).max(0.0));
+
+        forward_slope + self.kappa * self.initial_forward_rate(t)
+            + self.sigma.powi(2) / (2.0 * self.kappa) * (1.0 - (-2.0 * self.kappa * t).exp())
+    }
+
+    // Deterministic shift such that r(t) = x(t) + alpha(t) with x a zero-mean Ornstein-Uhlenbeck process
+    fn alpha(&self, t: f64) -> f64 {
+        self.initial_forward_rate(t) + self.sigma.powi(2) / (2.0 * self.kappa.powi(2)) * (1.0 - (-self.kappa * t).exp()).powi(2)
+    }
+
+    fn b(&self, tau: f64) -> f64 {
+        (1.0 - (-self.kappa * tau).exp()) / self.kappa
+    }
+}
+
+impl Simulate for HullWhiteProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("kappa", self.kappa)?;
+        check_volatility("sigma", self.sigma)?;
+        check_positive("t", self.t)?;
+        for &(time, discount_factor) in self.discount_curve.iter() {
+            check_finite("discount_curve time", time)?;
+            check_positive("discount_curve discount factor", discount_factor)?;
+        }
+        // `new` sorts the pillars, but processes built field by field or deserialized must already be in order
+        if let Some(window) = self.discount_curve.windows(2).find(|window| window[1].0 <= window[0].0) {
+            return Err(ProcessError::InvalidParameter { name: "discount_curve time", value: window[1].0, reason: "pillar times must be increasing" });
+        }
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut r_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut x = 0.0;
+
+        let decay = (-self.kappa * dt).exp();
+        let std_dev = self.sigma * ((1.0 - (-2.0 * self.kappa * dt).exp()) / (2.0 * self.kappa)).sqrt();
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for i in 1..=number_of_steps {
+            x = x * decay + std_dev * normal.sample(&mut rng);
+
+            r_path.push(x + self.alpha(i as f64 * dt));
+        }
+
+        r_path
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        short_rate_simulated_path(self, self.generate_price_path(number_of_steps))
+    }
+}
+
+impl ShortRateProcess for HullWhiteProcess {
+    fn initial_rate(&self) -> f64 {
+        self.initial_forward_rate(0.0)
+    }
+
+    fn time_horizon(&self) -> f64 {
+        self.t
+    }
+
+    fn zero_coupon_bond_price(&self, t: f64, maturity: f64, short_rate: f64) -> f64 {
+        let b = self.b(maturity - t);
+        let a = self.initial_discount_factor(maturity) / self.initial_discount_factor(t)
+            * (b * self.initial_forward_rate(t)
+                - self.sigma.powi(2) / (4.0 * self.kappa) * (1.0 - (-2.0 * self.kappa * t).exp()) * b.powi(2)).exp();
+
+        a * (-b * short_rate).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_process() -> HullWhiteProcess {
+        let discount_curve = vec![(0.5, 0.985), (1.0, 0.968), (2.0, 0.935), (5.0, 0.83)];
+        HullWhiteProcess::new(0.1, 0.01, discount_curve, 2.0)
+    }
+
+    #[test]
+    fn test_initial_curve_is_repriced() {
+        let process = create_process();
+
+        for &(t, df) in process.discount_curve.iter() {
+            let model_price = process.zero_coupon_bond_price(0.0, t, process.initial_rate());
+            assert!((model_price - df).abs() < 1e-12, "Model price {} does not match curve {} at {}", model_price, df, t);
+        }
+    }
+
+    #[test]
+    fn test_log_linear_interpolation() {
+        let process = create_process();
+        let df = process.initial_discount_factor(0.75);
+
+        assert!((df - (0.985f64 * 0.968).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_simulated_discount_factors_match_curve() {
+        let process = create_process();
+        let number_of_paths = 5000;
+
+        let mean_discount_factor = (0..number_of_paths)
+            .map(|_| process.generate_price_path(200))
+            .map(|r_path| *process.discount_factor_path(&r_path).last().unwrap())
+            .sum::<f64>() / number_of_paths as f64;
+
+        assert!((mean_discount_factor - 0.935).abs() < 2e-3, "Mean discount factor {} too far from curve", mean_discount_factor);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let error = HullWhiteProcess::try_new(0.1, 0.01, vec![(1.0, 0.97), (2.0, -0.9)], 2.0).err();
+        assert_eq!(error, Some(ProcessError::NonPositiveParameter { name: "discount_curve discount factor", value: -0.9 }));
+        assert_eq!(HullWhiteProcess::try_new(-0.1, 0.01, vec![], 2.0).err(), Some(ProcessError::NonPositiveParameter { name: "kappa", value: -0.1 }));
+        assert!(HullWhiteProcess::try_new(0.1, 0.01, vec![(2.0, 0.94), (1.0, 0.97)], 2.0).is_ok());
+        assert!(matches!(HullWhiteProcess::try_new(0.1, 0.01, vec![(1.0, 0.97), (1.0, 0.96)], 2.0), Err(ProcessError::InvalidParameter { name: "discount_curve time", .. })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&create_process()).unwrap();
+
+        assert_eq!(json, r#"{"kappa":0.1,"sigma":0.01,"discount_curve":[[0.5,0.985],[1.0,0.968],[2.0,0.935],[5.0,0.83]],"t":2.0}"#);
+        assert_eq!(serde_json::from_str::<HullWhiteProcess>(&json).unwrap().discount_curve, create_process().discount_curve);
+
+        let invalid_curve = json.replace("[2.0,0.935]", "[2.0,-0.935]");
+        assert!(serde_json::from_str::<HullWhiteProcess>(&invalid_curve).err().unwrap().to_string().contains("discount_curve discount factor"));
+
+        let unsorted_curve = json.replace("[0.5,0.985],[1.0,0.968]", "[1.0,0.968],[0.5,0.985]");
+        assert!(serde_json::from_str::<HullWhiteProcess>(&unsorted_curve).err().unwrap().to_string().contains("pillar times must be increasing"));
+    }
+}
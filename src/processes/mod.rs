@@ -1,6 +1,108 @@
+// Serde support that rejects values failing the `validate` of a trait, `Simulate` for processes and `Value` for
+// instruments. The type derives its field-wise (de)serialization as inherent functions with `serde(remote = "Self")`,
+// which these impls wrap.
+#[cfg(feature = "serde")]
+macro_rules! validated_serde {
+    ($process:ty) => {
+        crate::processes::validated_serde!($process, crate::processes::Simulate);
+    };
+    ($type:ty, $validating_trait:path) => {
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$type>::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <$type>::deserialize(deserializer)?;
+                <$type as $validating_trait>::validate(&value).map_err(serde::de::Error::custom)?;
+                Ok(value)
+            }
+        }
+    };
+}
+#[cfg(feature = "serde")]
+pub(crate) use validated_serde;
+
 pub mod heston_process;
 pub mod black_scholes_process;
+pub mod vasicek_process;
+pub mod cir_process;
+pub mod hull_white_process;
+pub mod ornstein_uhlenbeck_process;
+pub mod schwartz_process;
+pub mod schwartz_smith_process;
+pub mod variance_gamma_process;
+pub mod normal_inverse_gaussian_process;
+pub mod rough_bergomi_process;
+pub mod simulated_path;
+pub mod process_error;
+pub mod garch_model;
+pub mod garch_process;
+pub mod gjr_garch_process;
+pub mod egarch_process;
+
+use num_complex::Complex;
+
+use crate::processes::process_error::ProcessError;
+use crate::processes::simulated_path::{SimulatedPath, StateVariable};
 
 pub trait Simulate {
     fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64>;
-}
\ No newline at end of file
+
+    fn validate(&self) -> Result<(), ProcessError> {
+        Ok(())
+    }
+
+    fn try_generate_path(&self, number_of_steps: usize) -> Result<SimulatedPath, ProcessError> {
+        self.validate()?;
+        Ok(self.generate_path(number_of_steps))
+    }
+
+    // Processes with more than one state variable override this to expose all of them
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        SimulatedPath::from_spot(self.generate_price_path(number_of_steps))
+    }
+}
+
+// Processes whose log price has a known characteristic function under the risk-neutral measure
+pub trait CharacteristicFunction {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64>;
+
+    fn initial_price(&self) -> f64;
+
+    fn risk_free_rate(&self) -> f64;
+}
+
+pub trait ShortRateProcess: Simulate {
+    fn initial_rate(&self) -> f64;
+
+    fn time_horizon(&self) -> f64;
+
+    fn zero_coupon_bond_price(&self, t: f64, maturity: f64, short_rate: f64) -> f64;
+
+    // Discount factors from time zero to each step of a simulated short rate path, using the trapezoidal rule
+    fn discount_factor_path(&self, short_rate_path: &[f64]) -> Vec<f64> {
+        let dt = self.time_horizon() / short_rate_path.len() as f64;
+        let mut previous_rate = self.initial_rate();
+        let mut integrated_rate = 0.0;
+
+        short_rate_path.iter()
+            .map(|&r| {
+                integrated_rate += 0.5 * (previous_rate + r) * dt;
+                previous_rate = r;
+                (-integrated_rate).exp()
+            })
+            .collect()
+    }
+}
+
+// The short rate is also exposed as the spot component so that existing payoffs can be written on it
+pub(crate) fn short_rate_simulated_path<P: ShortRateProcess>(process: &P, short_rate_path: Vec<f64>) -> SimulatedPath {
+    let numeraire_path = process.discount_factor_path(&short_rate_path).iter().map(|df| 1.0 / df).collect();
+
+    SimulatedPath::from_spot(short_rate_path.clone())
+        .with_state(StateVariable::ShortRate, short_rate_path)
+        .with_state(StateVariable::Numeraire, numeraire_path)
+}
@@ -0,0 +1,113 @@
+extern crate rand;
+extern crate rand_distr;
+
+use num_complex::Complex;
+use rand::prelude::*;
+use rand_distr::{InverseGaussian, Normal};
+
+use crate::processes::{CharacteristicFunction, Simulate};
+use crate::processes::process_error::{check_finite, check_positive, ProcessError};
+
+// Normal Inverse Gaussian process: Brownian motion with drift evaluated at an inverse Gaussian business time
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct NormalInverseGaussianProcess {
+    pub s0: f64, // Initial asset price
+    pub r: f64, // Risk-free rate
+    pub alpha: f64, // Tail heaviness
+    pub beta: f64, // Skewness, with |beta| < alpha
+    pub delta: f64, // Scale
+    pub t: f64, // Time to maturity
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(NormalInverseGaussianProcess);
+
+impl NormalInverseGaussianProcess {
+    pub fn new(s0: f64, r: f64, alpha: f64, beta: f64, delta: f64, t: f64) -> NormalInverseGaussianProcess {
+        NormalInverseGaussianProcess { s0, r, alpha, beta, delta, t }
+    }
+
+    pub fn try_new(s0: f64, r: f64, alpha: f64, beta: f64, delta: f64, t: f64) -> Result<NormalInverseGaussianProcess, ProcessError> {
+        let process = NormalInverseGaussianProcess::new(s0, r, alpha, beta, delta, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    fn gamma(&self) -> f64 {
+        (self.alpha.powi(2) - self.beta.powi(2)).sqrt()
+    }
+
+    // Drift correction that makes the discounted price a martingale
+    fn omega(&self) -> f64 {
+        -self.delta * (self.gamma() - (self.alpha.powi(2) - (self.beta + 1.0).powi(2)).sqrt())
+    }
+}
+
+impl Simulate for NormalInverseGaussianProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_finite("r", self.r)?;
+        check_positive("alpha", self.alpha)?;
+        check_finite("beta", self.beta)?;
+        check_positive("delta", self.delta)?;
+        check_positive("t", self.t)?;
+        if self.beta.abs() >= self.alpha || (self.beta + 1.0).abs() >= self.alpha {
+            return Err(ProcessError::InvalidParameter { name: "beta", value: self.beta, reason: "|beta| and |beta + 1| must be smaller than alpha" });
+        }
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut s = self.s0;
+
+        let drift = (self.r + self.omega()) * dt;
+        let inverse_gaussian = InverseGaussian::new(self.delta * dt / self.gamma(), (self.delta * dt).powi(2)).unwrap();
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for _ in 0..number_of_steps {
+            let di = inverse_gaussian.sample(&mut rng);
+            let dx = self.beta * di + di.sqrt() * normal.sample(&mut rng);
+            s *= (drift + dx).exp();
+
+            s_path.push(s);
+        }
+
+        s_path
+    }
+}
+
+impl CharacteristicFunction for NormalInverseGaussianProcess {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+        let drift = i * u * (self.s0.ln() + (self.r + self.omega()) * t);
+        let exponent = self.delta * t * (self.gamma() - (self.alpha.powi(2) - (self.beta + i * u).powi(2)).sqrt());
+
+        (drift + exponent).exp()
+    }
+
+    fn initial_price(&self) -> f64 {
+        self.s0
+    }
+
+    fn risk_free_rate(&self) -> f64 {
+        self.r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(NormalInverseGaussianProcess::try_new(100.0, 0.05, 15.0, -3.0, 0.0, 1.0).err(), Some(ProcessError::NonPositiveParameter { name: "delta", value: 0.0 }));
+
+        // The moment generating function must exist at 1 for the martingale correction
+        assert!(matches!(NormalInverseGaussianProcess::try_new(100.0, 0.05, 2.0, 1.5, 0.5, 1.0), Err(ProcessError::InvalidParameter { name: "beta", .. })));
+    }
+}
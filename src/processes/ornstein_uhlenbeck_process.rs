@@ -0,0 +1,111 @@
+extern crate rand;
+extern crate rand_distr;
+
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::processes::Simulate;
+use crate::processes::process_error::{check_finite, check_positive, check_volatility, ProcessError};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct OrnsteinUhlenbeckProcess {
+    pub x0: f64, // Initial value
+    pub kappa: f64, // Speed of mean reversion
+    pub theta: f64, // Long-term mean
+    pub sigma: f64, // Volatility
+    pub t: f64, // Time to maturity
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(OrnsteinUhlenbeckProcess);
+
+impl OrnsteinUhlenbeckProcess {
+    pub fn new(x0: f64, kappa: f64, theta: f64, sigma: f64, t: f64) -> OrnsteinUhlenbeckProcess {
+        OrnsteinUhlenbeckProcess { x0, kappa, theta, sigma, t }
+    }
+
+    pub fn try_new(x0: f64, kappa: f64, theta: f64, sigma: f64, t: f64) -> Result<OrnsteinUhlenbeckProcess, ProcessError> {
+        let process = OrnsteinUhlenbeckProcess::new(x0, kappa, theta, sigma, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn expected_value(&self, maturity: f64) -> f64 {
+        let decay = (-self.kappa * maturity).exp();
+        self.x0 * decay + self.theta * (1.0 - decay)
+    }
+
+    pub fn variance(&self, maturity: f64) -> f64 {
+        self.sigma.powi(2) * (1.0 - (-2.0 * self.kappa * maturity).exp()) / (2.0 * self.kappa)
+    }
+
+    // The process is used directly as the price, so the risk-neutral futures price is its expectation
+    pub fn futures_price(&self, maturity: f64) -> f64 {
+        self.expected_value(maturity)
+    }
+}
+
+impl Simulate for OrnsteinUhlenbeckProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_finite("x0", self.x0)?;
+        check_positive("kappa", self.kappa)?;
+        check_finite("theta", self.theta)?;
+        check_volatility("sigma", self.sigma)?;
+        check_positive("t", self.t)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut x_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut x = self.x0;
+
+        let decay = (-self.kappa * dt).exp();
+        let std_dev = self.variance(dt).sqrt();
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for _ in 0..number_of_steps {
+            x = x * decay + self.theta * (1.0 - decay) + std_dev * normal.sample(&mut rng);
+
+            x_path.push(x);
+        }
+
+        x_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_futures_price_at_zero_maturity_is_initial_value() {
+        let process = OrnsteinUhlenbeckProcess::new(20.0, 2.0, 25.0, 3.0, 1.0);
+        assert!((process.futures_price(0.0) - 20.0).abs() < 1e-12);
+        assert!((process.futures_price(50.0) - 25.0).abs() < 1e-12);
+        assert!(process.variance(0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_simulated_moments() {
+        let process = OrnsteinUhlenbeckProcess::new(20.0, 2.0, 25.0, 3.0, 1.0);
+        let number_of_paths = 20000;
+
+        let terminal_values: Vec<f64> = (0..number_of_paths).map(|_| *process.generate_price_path(12).last().unwrap()).collect();
+        let mean = terminal_values.iter().sum::<f64>() / number_of_paths as f64;
+        let variance = terminal_values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (number_of_paths - 1) as f64;
+
+        assert!((mean - process.futures_price(1.0)).abs() < 0.05, "Mean {} too far from futures price {}", mean, process.futures_price(1.0));
+        assert!((variance / process.variance(1.0) - 1.0).abs() < 0.05, "Variance {} too far from {}", variance, process.variance(1.0));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(OrnsteinUhlenbeckProcess::try_new(20.0, 2.0, 25.0, f64::NAN, 1.0), Err(ProcessError::NonFiniteParameter { name: "sigma", .. })));
+        assert_eq!(OrnsteinUhlenbeckProcess::try_new(20.0, -2.0, 25.0, 3.0, 1.0).err(), Some(ProcessError::NonPositiveParameter { name: "kappa", value: -2.0 }));
+    }
+}
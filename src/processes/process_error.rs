@@ -0,0 +1,75 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ProcessError {
+    NonFiniteParameter { name: &'static str, value: f64 },
+    NonPositiveParameter { name: &'static str, value: f64 },
+    NegativeParameter { name: &'static str, value: f64 },
+    NegativeVolatility { name: &'static str, value: f64 },
+    NegativeVariance { name: &'static str, value: f64 },
+    CorrelationOutOfRange { name: &'static str, value: f64 },
+    InvalidParameter { name: &'static str, value: f64, reason: &'static str },
+    InsufficientData { required: usize, provided: usize },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ProcessWarning {
+    // 2 kappa theta < sigma^2: the variance can reach zero and discretisation schemes lose accuracy
+    FellerConditionViolated { two_kappa_theta: f64, sigma_squared: f64 },
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::NonFiniteParameter { name, value } => write!(f, "Parameter {} must be finite, got {}.", name, value),
+            ProcessError::NonPositiveParameter { name, value } => write!(f, "Parameter {} must be positive, got {}.", name, value),
+            ProcessError::NegativeParameter { name, value } => write!(f, "Parameter {} must not be negative, got {}.", name, value),
+            ProcessError::NegativeVolatility { name, value } => write!(f, "Volatility {} must not be negative, got {}.", name, value),
+            ProcessError::NegativeVariance { name, value } => write!(f, "Variance {} must not be negative, got {}.", name, value),
+            ProcessError::CorrelationOutOfRange { name, value } => write!(f, "Correlation {} must lie in [-1, 1], got {}.", name, value),
+            ProcessError::InvalidParameter { name, value, reason } => write!(f, "Invalid parameter {} = {}: {}.", name, value, reason),
+            ProcessError::InsufficientData { required, provided } => write!(f, "At least {} observations are required, got {}.", required, provided),
+        }
+    }
+}
+
+impl Error for ProcessError {}
+
+impl fmt::Display for ProcessWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessWarning::FellerConditionViolated { two_kappa_theta, sigma_squared } =>
+                write!(f, "Feller condition violated: 2 kappa theta = {} < sigma^2 = {}.", two_kappa_theta, sigma_squared),
+        }
+    }
+}
+
+pub(crate) fn check_finite(name: &'static str, value: f64) -> Result<(), ProcessError> {
+    if value.is_finite() { Ok(()) } else { Err(ProcessError::NonFiniteParameter { name, value }) }
+}
+
+pub(crate) fn check_positive(name: &'static str, value: f64) -> Result<(), ProcessError> {
+    check_finite(name, value)?;
+    if value > 0.0 { Ok(()) } else { Err(ProcessError::NonPositiveParameter { name, value }) }
+}
+
+pub(crate) fn check_non_negative(name: &'static str, value: f64) -> Result<(), ProcessError> {
+    check_finite(name, value)?;
+    if value >= 0.0 { Ok(()) } else { Err(ProcessError::NegativeParameter { name, value }) }
+}
+
+pub(crate) fn check_volatility(name: &'static str, value: f64) -> Result<(), ProcessError> {
+    check_finite(name, value)?;
+    if value >= 0.0 { Ok(()) } else { Err(ProcessError::NegativeVolatility { name, value }) }
+}
+
+pub(crate) fn check_variance(name: &'static str, value: f64) -> Result<(), ProcessError> {
+    check_finite(name, value)?;
+    if value >= 0.0 { Ok(()) } else { Err(ProcessError::NegativeVariance { name, value }) }
+}
+
+pub(crate) fn check_correlation(name: &'static str, value: f64) -> Result<(), ProcessError> {
+    check_finite(name, value)?;
+    if (-1.0..=1.0).contains(&value) { Ok(()) } else { Err(ProcessError::CorrelationOutOfRange { name, value }) }
+}
@@ -0,0 +1,168 @@
+extern crate rand;
+extern crate rand_distr;
+
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::processes::Simulate;
+use crate::processes::simulated_path::{SimulatedPath, StateVariable};
+use crate::processes::process_error::{check_correlation, check_finite, check_positive, check_variance, check_volatility, ProcessError};
+
+// Rough Bergomi model of Bayer, Friz and Gatheral, simulated with the hybrid scheme of Bennedsen, Lunde and Pakkanen
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct RoughBergomiProcess {
+    pub s0: f64, // Initial asset price
+    pub r: f64, // Risk-free rate
+    pub xi0: f64, // Flat initial forward variance curve
+    pub eta: f64, // Volatility of variance
+    pub hurst: f64, // Hurst exponent of the variance driver, in (0, 1/2)
+    pub rho: f64, // Correlation between the asset and variance Brownian motions
+    pub t: f64, // Time to maturity
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(RoughBergomiProcess);
+
+impl RoughBergomiProcess {
+    pub fn new(s0: f64, r: f64, xi0: f64, eta: f64, hurst: f64, rho: f64, t: f64) -> RoughBergomiProcess {
+        RoughBergomiProcess { s0, r, xi0, eta, hurst, rho, t }
+    }
+
+    pub fn try_new(s0: f64, r: f64, xi0: f64, eta: f64, hurst: f64, rho: f64, t: f64) -> Result<RoughBergomiProcess, ProcessError> {
+        let process = RoughBergomiProcess::new(s0, r, xi0, eta, hurst, rho, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    // Returns the spot and instantaneous variance paths, sampled at the end of each step
+    pub fn generate_paths(&self, number_of_steps: usize) -> (Vec<f64>, Vec<f64>) {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let alpha = self.hurst - 0.5;
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        // Joint law of the Brownian increment and the singular part of the Volterra integral over one step
+        let var_dw = dt;
+        let var_y = dt.powf(2.0 * alpha + 1.0) / (2.0 * alpha + 1.0);
+        let cov = dt.powf(alpha + 1.0) / (alpha + 1.0);
+        let chol_21 = cov / var_dw.sqrt();
+        let chol_22 = (var_y - chol_21.powi(2)).max(0.0).sqrt();
+
+        let mut dw: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut y: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut dw_perp: Vec<f64> = Vec::with_capacity(number_of_steps);
+        for _ in 0..number_of_steps {
+            let z1 = normal.sample(&mut rng);
+            let z2 = normal.sample(&mut rng);
+            dw.push(var_dw.sqrt() * z1);
+            y.push(chol_21 * z1 + chol_22 * z2);
+            dw_perp.push(dt.sqrt() * normal.sample(&mut rng));
+        }
+
+        // Riemann-sum weights evaluated at the optimal discretisation points b_k
+        let weights: Vec<f64> = (0..=number_of_steps)
+            .map(|k| {
+                if k < 2 {
+                    0.0
+                } else {
+                    let k = k as f64;
+                    let b = ((k.powf(alpha + 1.0) - (k - 1.0).powf(alpha + 1.0)) / (alpha + 1.0)).powf(1.0 / alpha);
+                    (b * dt).powf(alpha)
+                }
+            })
+            .collect();
+
+        let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut v_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut log_s = self.s0.ln();
+        let mut v = self.xi0;
+
+        for i in 1..=number_of_steps {
+            let db = self.rho * dw[i - 1] + (1.0 - self.rho.powi(2)).sqrt() * dw_perp[i - 1];
+            log_s += (self.r - 0.5 * v) * dt + v.sqrt() * db;
+
+            let volterra = y[i - 1] + (2..=i).map(|k| weights[k] * dw[i - k]).sum::<f64>();
+            let time = i as f64 * dt;
+            v = self.xi0 * (self.eta * (2.0 * self.hurst).sqrt() * volterra - 0.5 * self.eta.powi(2) * time.powf(2.0 * self.hurst)).exp();
+
+            s_path.push(log_s.exp());
+            v_path.push(v);
+        }
+
+        (s_path, v_path)
+    }
+}
+
+impl Simulate for RoughBergomiProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_finite("r", self.r)?;
+        check_variance("xi0", self.xi0)?;
+        check_volatility("eta", self.eta)?;
+        check_positive("hurst", self.hurst)?;
+        check_correlation("rho", self.rho)?;
+        check_positive("t", self.t)?;
+        if self.hurst >= 0.5 {
+            return Err(ProcessError::InvalidParameter { name: "hurst", value: self.hurst, reason: "the Hurst exponent must lie in (0, 1/2)" });
+        }
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.generate_paths(number_of_steps).0
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        let (s_path, v_path) = self.generate_paths(number_of_steps);
+        SimulatedPath::from_spot(s_path).with_state(StateVariable::Variance, v_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variance_matches_forward_variance() {
+        let process = RoughBergomiProcess::new(100.0, 0.0, 0.04, 1.0, 0.1, -0.9, 1.0);
+        let number_of_paths = 20000;
+
+        let mean_terminal_variance = (0..number_of_paths)
+            .map(|_| *process.generate_paths(50).1.last().unwrap())
+            .sum::<f64>() / number_of_paths as f64;
+
+        assert!((mean_terminal_variance - 0.04).abs() < 0.002, "Mean variance {} too far from forward variance", mean_terminal_variance);
+    }
+
+    #[test]
+    fn test_discounted_spot_is_martingale() {
+        let process = RoughBergomiProcess::new(100.0, 0.03, 0.04, 1.9, 0.1, -0.9, 1.0);
+        let number_of_paths = 20000;
+
+        let mean_terminal_spot = (0..number_of_paths)
+            .map(|_| *process.generate_price_path(50).last().unwrap())
+            .sum::<f64>() / number_of_paths as f64;
+
+        let forward = 100.0 * 0.03f64.exp();
+        assert!((mean_terminal_spot / forward - 1.0).abs() < 0.01, "Mean spot {} too far from forward {}", mean_terminal_spot, forward);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(RoughBergomiProcess::try_new(100.0, 0.0, 0.04, 1.9, 0.6, -0.9, 1.0), Err(ProcessError::InvalidParameter { name: "hurst", .. })));
+        assert_eq!(RoughBergomiProcess::try_new(100.0, 0.0, 0.04, 1.9, 0.0, -0.9, 1.0).err(), Some(ProcessError::NonPositiveParameter { name: "hurst", value: 0.0 }));
+        assert_eq!(RoughBergomiProcess::try_new(100.0, 0.0, 0.04, 1.9, 0.1, -1.2, 1.0).err(), Some(ProcessError::CorrelationOutOfRange { name: "rho", value: -1.2 }));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&RoughBergomiProcess::new(100.0, 0.0, 0.04, 1.9, 0.1, -0.9, 1.0)).unwrap();
+        assert_eq!(json, r#"{"s0":100.0,"r":0.0,"xi0":0.04,"eta":1.9,"hurst":0.1,"rho":-0.9,"t":1.0}"#);
+        assert_eq!(serde_json::to_string(&serde_json::from_str::<RoughBergomiProcess>(&json).unwrap()).unwrap(), json);
+
+        assert!(serde_json::from_str::<RoughBergomiProcess>(&json.replace(r#""hurst":0.1"#, r#""hurst":0.6"#)).is_err());
+    }
+}
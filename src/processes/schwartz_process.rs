@@ -0,0 +1,111 @@
+extern crate rand;
+extern crate rand_distr;
+
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::processes::Simulate;
+use crate::processes::process_error::{check_finite, check_positive, check_volatility, ProcessError};
+
+// Schwartz (1997) one-factor model: the log spot price follows a mean-reverting Ornstein-Uhlenbeck process
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct SchwartzProcess {
+    pub s0: f64, // Initial spot price
+    pub kappa: f64, // Speed of mean reversion of the log price
+    pub alpha: f64, // Risk-neutral long-term mean of the log price
+    pub sigma: f64, // Volatility of the log price
+    pub t: f64, // Time to maturity
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(SchwartzProcess);
+
+impl SchwartzProcess {
+    pub fn new(s0: f64, kappa: f64, alpha: f64, sigma: f64, t: f64) -> SchwartzProcess {
+        SchwartzProcess { s0, kappa, alpha, sigma, t }
+    }
+
+    pub fn try_new(s0: f64, kappa: f64, alpha: f64, sigma: f64, t: f64) -> Result<SchwartzProcess, ProcessError> {
+        let process = SchwartzProcess::new(s0, kappa, alpha, sigma, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn futures_price(&self, maturity: f64) -> f64 {
+        let decay = (-self.kappa * maturity).exp();
+        let log_mean = decay * self.s0.ln() + (1.0 - decay) * self.alpha;
+        let log_variance = self.sigma.powi(2) * (1.0 - (-2.0 * self.kappa * maturity).exp()) / (2.0 * self.kappa);
+
+        (log_mean + 0.5 * log_variance).exp()
+    }
+}
+
+impl Simulate for SchwartzProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_positive("kappa", self.kappa)?;
+        check_finite("alpha", self.alpha)?;
+        check_volatility("sigma", self.sigma)?;
+        check_positive("t", self.t)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut x = self.s0.ln();
+
+        let decay = (-self.kappa * dt).exp();
+        let std_dev = self.sigma * ((1.0 - (-2.0 * self.kappa * dt).exp()) / (2.0 * self.kappa)).sqrt();
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for _ in 0..number_of_steps {
+            x = x * decay + self.alpha * (1.0 - decay) + std_dev * normal.sample(&mut rng);
+
+            s_path.push(x.exp());
+        }
+
+        s_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_futures_price_at_zero_maturity_is_spot() {
+        let process = SchwartzProcess::new(50.0, 1.5, 4.0, 0.4, 1.0);
+        assert!((process.futures_price(0.0) - 50.0).abs() < 1e-12);
+
+        // Far maturities converge to the long-term level with the stationary log variance
+        let long_term = (4.0 + 0.5 * 0.4f64.powi(2) / (2.0 * 1.5)).exp();
+        assert!((process.futures_price(50.0) / long_term - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_simulated_terminal_price_matches_futures_price() {
+        let process = SchwartzProcess::new(50.0, 1.5, 4.0, 0.4, 1.0);
+        let number_of_paths = 20000;
+
+        let log_prices: Vec<f64> = (0..number_of_paths).map(|_| process.generate_price_path(12).last().unwrap().ln()).collect();
+        let mean_terminal_price = log_prices.iter().map(|x| x.exp()).sum::<f64>() / number_of_paths as f64;
+        let log_mean = log_prices.iter().sum::<f64>() / number_of_paths as f64;
+        let log_variance = log_prices.iter().map(|x| (x - log_mean).powi(2)).sum::<f64>() / (number_of_paths - 1) as f64;
+
+        let futures_price = process.futures_price(1.0);
+        let expected_log_variance = 0.4f64.powi(2) * (1.0 - (-2.0 * 1.5f64).exp()) / (2.0 * 1.5);
+        assert!((mean_terminal_price / futures_price - 1.0).abs() < 0.01, "Mean terminal price {} too far from futures price {}", mean_terminal_price, futures_price);
+        assert!((log_variance / expected_log_variance - 1.0).abs() < 0.05, "Log variance {} too far from {}", log_variance, expected_log_variance);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(SchwartzProcess::try_new(0.0, 1.5, 4.0, 0.4, 1.0).err(), Some(ProcessError::NonPositiveParameter { name: "s0", value: 0.0 }));
+        assert_eq!(SchwartzProcess::try_new(50.0, 1.5, 4.0, -0.4, 1.0).err(), Some(ProcessError::NegativeVolatility { name: "sigma", value: -0.4 }));
+    }
+}
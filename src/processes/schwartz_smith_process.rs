@@ -0,0 +1,140 @@
+extern crate rand;
+extern crate rand_distr;
+
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::processes::Simulate;
+use crate::processes::process_error::{check_correlation, check_finite, check_positive, check_volatility, ProcessError};
+
+// Schwartz-Smith (2000) two-factor model: log S = chi + xi with mean-reverting short-term deviations chi
+// and an arithmetic Brownian equilibrium level xi, both under the risk-neutral measure
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct SchwartzSmithProcess {
+    pub chi0: f64, // Initial short-term deviation
+    pub xi0: f64, // Initial equilibrium log price
+    pub kappa: f64, // Speed of mean reversion of the short-term deviation
+    pub sigma_chi: f64, // Volatility of the short-term deviation
+    pub lambda_chi: f64, // Market price of short-term risk
+    pub mu_xi: f64, // Risk-neutral drift of the equilibrium level
+    pub sigma_xi: f64, // Volatility of the equilibrium level
+    pub rho: f64, // Correlation between the two factors
+    pub t: f64, // Time to maturity
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(SchwartzSmithProcess);
+
+impl SchwartzSmithProcess {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(chi0: f64, xi0: f64, kappa: f64, sigma_chi: f64, lambda_chi: f64, mu_xi: f64, sigma_xi: f64, rho: f64, t: f64) -> SchwartzSmithProcess {
+        SchwartzSmithProcess { chi0, xi0, kappa, sigma_chi, lambda_chi, mu_xi, sigma_xi, rho, t }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(chi0: f64, xi0: f64, kappa: f64, sigma_chi: f64, lambda_chi: f64, mu_xi: f64, sigma_xi: f64, rho: f64, t: f64) -> Result<SchwartzSmithProcess, ProcessError> {
+        let process = SchwartzSmithProcess::new(chi0, xi0, kappa, sigma_chi, lambda_chi, mu_xi, sigma_xi, rho, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    pub fn spot_price(&self) -> f64 {
+        (self.chi0 + self.xi0).exp()
+    }
+
+    pub fn futures_price(&self, maturity: f64) -> f64 {
+        let decay = (-self.kappa * maturity).exp();
+        let drift = self.mu_xi * maturity - (1.0 - decay) * self.lambda_chi / self.kappa;
+        let log_variance = (1.0 - decay.powi(2)) * self.sigma_chi.powi(2) / (2.0 * self.kappa)
+            + self.sigma_xi.powi(2) * maturity
+            + 2.0 * (1.0 - decay) * self.rho * self.sigma_chi * self.sigma_xi / self.kappa;
+
+        (decay * self.chi0 + self.xi0 + drift + 0.5 * log_variance).exp()
+    }
+}
+
+impl Simulate for SchwartzSmithProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_finite("chi0", self.chi0)?;
+        check_finite("xi0", self.xi0)?;
+        check_positive("kappa", self.kappa)?;
+        check_volatility("sigma_chi", self.sigma_chi)?;
+        check_finite("lambda_chi", self.lambda_chi)?;
+        check_finite("mu_xi", self.mu_xi)?;
+        check_volatility("sigma_xi", self.sigma_xi)?;
+        check_correlation("rho", self.rho)?;
+        check_positive("t", self.t)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut chi = self.chi0;
+        let mut xi = self.xi0;
+
+        // Exact joint Gaussian transition of (chi, xi) over one step
+        let decay = (-self.kappa * dt).exp();
+        let std_dev_chi = self.sigma_chi * ((1.0 - decay.powi(2)) / (2.0 * self.kappa)).sqrt();
+        let std_dev_xi = self.sigma_xi * dt.sqrt();
+        let covariance = self.rho * self.sigma_chi * self.sigma_xi * (1.0 - decay) / self.kappa;
+        let correlation = if std_dev_chi > 0.0 && std_dev_xi > 0.0 { covariance / (std_dev_chi * std_dev_xi) } else { 0.0 };
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for _ in 0..number_of_steps {
+            let z_chi = normal.sample(&mut rng);
+            let z_xi = correlation * z_chi + (1.0 - correlation.powi(2)).sqrt() * normal.sample(&mut rng);
+
+            chi = chi * decay - self.lambda_chi * (1.0 - decay) / self.kappa + std_dev_chi * z_chi;
+            xi += self.mu_xi * dt + std_dev_xi * z_xi;
+
+            s_path.push((chi + xi).exp());
+        }
+
+        s_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_futures_price_at_zero_maturity_is_spot() {
+        let process = SchwartzSmithProcess::new(0.1, 4.0, 1.5, 0.3, 0.05, 0.01, 0.15, 0.3, 1.0);
+        assert!((process.futures_price(0.0) - process.spot_price()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_simulated_terminal_price_matches_futures_price() {
+        let process = SchwartzSmithProcess::new(0.1, 4.0, 1.5, 0.3, 0.05, 0.01, 0.15, 0.3, 1.0);
+        let number_of_paths = 20000;
+
+        let mean_terminal_price = (0..number_of_paths)
+            .map(|_| *process.generate_price_path(12).last().unwrap())
+            .sum::<f64>() / number_of_paths as f64;
+
+        let futures_price = process.futures_price(1.0);
+        assert!((mean_terminal_price / futures_price - 1.0).abs() < 0.01, "Mean terminal price {} too far from futures price {}", mean_terminal_price, futures_price);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let error = SchwartzSmithProcess::try_new(0.1, 4.0, 1.5, 0.3, 0.05, 0.01, 0.15, 2.0, 1.0).err();
+        assert_eq!(error, Some(ProcessError::CorrelationOutOfRange { name: "rho", value: 2.0 }));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&SchwartzSmithProcess::new(0.1, 4.0, 1.5, 0.3, 0.05, 0.01, 0.15, 0.3, 1.0)).unwrap();
+        assert_eq!(json, r#"{"chi0":0.1,"xi0":4.0,"kappa":1.5,"sigma_chi":0.3,"lambda_chi":0.05,"mu_xi":0.01,"sigma_xi":0.15,"rho":0.3,"t":1.0}"#);
+        assert_eq!(serde_json::to_string(&serde_json::from_str::<SchwartzSmithProcess>(&json).unwrap()).unwrap(), json);
+
+        assert!(serde_json::from_str::<SchwartzSmithProcess>(&json.replace(r#""rho":0.3"#, r#""rho":2.0"#)).is_err());
+    }
+}
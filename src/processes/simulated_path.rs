@@ -0,0 +1,97 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StateVariable {
+    Spot,
+    Variance,
+    ShortRate,
+    Numeraire,
+}
+
+// Simulated values of several state variables, each sampled at the end of every time step
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulatedPath {
+    states: Vec<(StateVariable, Vec<f64>)>,
+}
+
+impl SimulatedPath {
+    pub fn new() -> Self {
+        SimulatedPath { states: Vec::new() }
+    }
+
+    pub fn from_spot(spot_path: Vec<f64>) -> Self {
+        SimulatedPath::new().with_state(StateVariable::Spot, spot_path)
+    }
+
+    pub fn with_state(mut self, variable: StateVariable, values: Vec<f64>) -> Self {
+        self.insert(variable, values);
+        self
+    }
+
+    pub fn insert(&mut self, variable: StateVariable, values: Vec<f64>) {
+        match self.states.iter_mut().find(|(existing, _)| *existing == variable) {
+            Some((_, existing_values)) => *existing_values = values,
+            None => self.states.push((variable, values)),
+        }
+    }
+
+    pub fn state(&self, variable: StateVariable) -> Option<&[f64]> {
+        self.states.iter()
+            .find(|(existing, _)| *existing == variable)
+            .map(|(_, values)| values.as_slice())
+    }
+
+    // Spot component used by single-asset payoffs, empty if the process does not simulate a spot
+    pub fn spot(&self) -> &[f64] {
+        self.state(StateVariable::Spot).unwrap_or(&[])
+    }
+
+    pub fn value_at(&self, variable: StateVariable, step: usize) -> Option<f64> {
+        self.state(variable)?.get(step).copied()
+    }
+
+    pub fn state_variables(&self) -> Vec<StateVariable> {
+        self.states.iter().map(|(variable, _)| *variable).collect()
+    }
+
+    pub fn number_of_steps(&self) -> usize {
+        self.states.iter().map(|(_, values)| values.len()).max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spot_path_conversion() {
+        let path = SimulatedPath::from_spot(vec![100.0, 101.0, 99.5]);
+
+        assert_eq!(path.spot(), &[100.0, 101.0, 99.5]);
+        assert_eq!(path.state(StateVariable::Spot), Some(&[100.0, 101.0, 99.5][..]));
+        assert_eq!(path.state_variables(), vec![StateVariable::Spot]);
+        assert_eq!(path.number_of_steps(), 3);
+    }
+
+    #[test]
+    fn test_state_lookup() {
+        let mut path = SimulatedPath::from_spot(vec![100.0, 101.0]).with_state(StateVariable::Variance, vec![0.04, 0.05]);
+        assert_eq!(path.value_at(StateVariable::Variance, 1), Some(0.05));
+        assert_eq!(path.state_variables(), vec![StateVariable::Spot, StateVariable::Variance]);
+
+        // Inserting an existing state replaces its values in place
+        path.insert(StateVariable::Variance, vec![0.03, 0.02, 0.01]);
+        assert_eq!(path.state(StateVariable::Variance), Some(&[0.03, 0.02, 0.01][..]));
+        assert_eq!(path.state_variables().len(), 2);
+        assert_eq!(path.number_of_steps(), 3);
+    }
+
+    #[test]
+    fn test_missing_state() {
+        let path = SimulatedPath::new().with_state(StateVariable::ShortRate, vec![0.05]);
+
+        assert_eq!(path.state(StateVariable::Numeraire), None);
+        assert_eq!(path.value_at(StateVariable::Numeraire, 0), None);
+        assert_eq!(path.value_at(StateVariable::ShortRate, 1), None);
+        assert!(path.spot().is_empty());
+        assert_eq!(SimulatedPath::default().number_of_steps(), 0);
+    }
+}
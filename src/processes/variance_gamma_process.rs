@@ -0,0 +1,121 @@
+extern crate rand;
+extern crate rand_distr;
+
+use num_complex::Complex;
+use rand::prelude::*;
+use rand_distr::{Gamma, Normal};
+
+use crate::processes::{CharacteristicFunction, Simulate};
+use crate::processes::process_error::{check_finite, check_positive, check_volatility, ProcessError};
+
+// Variance Gamma process: Brownian motion with drift evaluated at a gamma-distributed business time
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct VarianceGammaProcess {
+    pub s0: f64, // Initial asset price
+    pub r: f64, // Risk-free rate
+    pub sigma: f64, // Volatility of the subordinated Brownian motion
+    pub nu: f64, // Variance rate of the gamma time change
+    pub theta: f64, // Drift of the subordinated Brownian motion
+    pub t: f64, // Time to maturity
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(VarianceGammaProcess);
+
+impl VarianceGammaProcess {
+    pub fn new(s0: f64, r: f64, sigma: f64, nu: f64, theta: f64, t: f64) -> VarianceGammaProcess {
+        VarianceGammaProcess { s0, r, sigma, nu, theta, t }
+    }
+
+    pub fn try_new(s0: f64, r: f64, sigma: f64, nu: f64, theta: f64, t: f64) -> Result<VarianceGammaProcess, ProcessError> {
+        let process = VarianceGammaProcess::new(s0, r, sigma, nu, theta, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    // Drift correction that makes the discounted price a martingale
+    fn omega(&self) -> f64 {
+        (1.0 - self.theta * self.nu - self.sigma.powi(2) * self.nu / 2.0).ln() / self.nu
+    }
+}
+
+impl Simulate for VarianceGammaProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_positive("s0", self.s0)?;
+        check_finite("r", self.r)?;
+        check_volatility("sigma", self.sigma)?;
+        check_positive("nu", self.nu)?;
+        check_finite("theta", self.theta)?;
+        check_positive("t", self.t)?;
+        let martingale_base = 1.0 - self.theta * self.nu - self.sigma.powi(2) * self.nu / 2.0;
+        if martingale_base <= 0.0 {
+            return Err(ProcessError::InvalidParameter { name: "nu", value: self.nu, reason: "1 - theta nu - sigma^2 nu / 2 must be positive for the martingale correction" });
+        }
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut s_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut s = self.s0;
+
+        let drift = (self.r + self.omega()) * dt;
+        let gamma = Gamma::new(dt / self.nu, self.nu).unwrap();
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for _ in 0..number_of_steps {
+            let dg = gamma.sample(&mut rng);
+            let dx = self.theta * dg + self.sigma * dg.sqrt() * normal.sample(&mut rng);
+            s *= (drift + dx).exp();
+
+            s_path.push(s);
+        }
+
+        s_path
+    }
+}
+
+impl CharacteristicFunction for VarianceGammaProcess {
+    fn characteristic_function(&self, u: Complex<f64>, t: f64) -> Complex<f64> {
+        let i: Complex<f64> = Complex::i();
+        let drift = i * u * (self.s0.ln() + (self.r + self.omega()) * t);
+        let base = 1.0 - i * u * self.theta * self.nu + 0.5 * self.sigma.powi(2) * self.nu * u * u;
+
+        drift.exp() * base.powf(-t / self.nu)
+    }
+
+    fn initial_price(&self) -> f64 {
+        self.s0
+    }
+
+    fn risk_free_rate(&self) -> f64 {
+        self.r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(VarianceGammaProcess::try_new(100.0, 0.05, 0.2, 0.0, -0.1, 1.0).err(), Some(ProcessError::NonPositiveParameter { name: "nu", value: 0.0 }));
+
+        // The martingale correction is undefined once 1 - theta nu - sigma^2 nu / 2 is not positive
+        assert!(matches!(VarianceGammaProcess::try_new(100.0, 0.05, 0.2, 1.0, 1.0, 1.0), Err(ProcessError::InvalidParameter { name: "nu", .. })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&VarianceGammaProcess::new(100.0, 0.05, 0.2, 0.3, -0.1, 1.0)).unwrap();
+        assert_eq!(json, r#"{"s0":100.0,"r":0.05,"sigma":0.2,"nu":0.3,"theta":-0.1,"t":1.0}"#);
+        assert_eq!(serde_json::to_string(&serde_json::from_str::<VarianceGammaProcess>(&json).unwrap()).unwrap(), json);
+
+        let error = serde_json::from_str::<VarianceGammaProcess>(&json.replace(r#""nu":0.3"#, r#""nu":0.0"#)).err().unwrap();
+        assert!(error.to_string().contains("Parameter nu must be positive, got 0."));
+    }
+}
@@ -0,0 +1,148 @@
+extern crate rand;
+extern crate rand_distr;
+
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::processes::{short_rate_simulated_path, ShortRateProcess, Simulate};
+use crate::processes::simulated_path::SimulatedPath;
+use crate::processes::process_error::{check_finite, check_positive, check_volatility, ProcessError};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(remote = "Self"))]
+pub struct VasicekProcess {
+    pub r0: f64, // Initial short rate
+    pub kappa: f64, // Speed of mean reversion
+    pub theta: f64, // Long-term mean of the short rate
+    pub sigma: f64, // Volatility of the short rate
+    pub t: f64, // Time horizon of the simulation
+}
+
+#[cfg(feature = "serde")]
+crate::processes::validated_serde!(VasicekProcess);
+
+impl VasicekProcess {
+    pub fn new(r0: f64, kappa: f64, theta: f64, sigma: f64, t: f64) -> VasicekProcess {
+        VasicekProcess { r0, kappa, theta, sigma, t }
+    }
+
+    pub fn try_new(r0: f64, kappa: f64, theta: f64, sigma: f64, t: f64) -> Result<VasicekProcess, ProcessError> {
+        let process = VasicekProcess::new(r0, kappa, theta, sigma, t);
+        process.validate()?;
+        Ok(process)
+    }
+
+    fn b(&self, tau: f64) -> f64 {
+        (1.0 - (-self.kappa * tau).exp()) / self.kappa
+    }
+}
+
+impl Simulate for VasicekProcess {
+    fn validate(&self) -> Result<(), ProcessError> {
+        check_finite("r0", self.r0)?;
+        check_positive("kappa", self.kappa)?;
+        check_finite("theta", self.theta)?;
+        check_volatility("sigma", self.sigma)?;
+        check_positive("t", self.t)?;
+        Ok(())
+    }
+
+    fn generate_price_path(&self, number_of_steps: usize) -> Vec<f64> {
+        self.validate().unwrap_or_else(|error| panic!("{}", error));
+
+        let mut rng = thread_rng();
+        let dt = self.t / number_of_steps as f64;
+        let mut r_path: Vec<f64> = Vec::with_capacity(number_of_steps);
+        let mut r = self.r0;
+
+        // Exact transition of the Ornstein-Uhlenbeck short rate over one step
+        let decay = (-self.kappa * dt).exp();
+        let std_dev = self.sigma * ((1.0 - (-2.0 * self.kappa * dt).exp()) / (2.0 * self.kappa)).sqrt();
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        for _ in 0..number_of_steps {
+            r = r * decay + self.theta * (1.0 - decay) + std_dev * normal.sample(&mut rng);
+
+            r_path.push(r);
+        }
+
+        r_path
+    }
+
+    fn generate_path(&self, number_of_steps: usize) -> SimulatedPath {
+        short_rate_simulated_path(self, self.generate_price_path(number_of_steps))
+    }
+}
+
+impl ShortRateProcess for VasicekProcess {
+    fn initial_rate(&self) -> f64 {
+        self.r0
+    }
+
+    fn time_horizon(&self) -> f64 {
+        self.t
+    }
+
+    fn zero_coupon_bond_price(&self, t: f64, maturity: f64, short_rate: f64) -> f64 {
+        let tau = maturity - t;
+        let b = self.b(tau);
+        let a = ((self.theta - self.sigma.powi(2) / (2.0 * self.kappa.powi(2))) * (b - tau)
+            - self.sigma.powi(2) * b.powi(2) / (4.0 * self.kappa)).exp();
+
+        a * (-b * short_rate).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_process() -> VasicekProcess {
+        VasicekProcess::new(0.03, 0.5, 0.06, 0.02, 2.0)
+    }
+
+    #[test]
+    fn test_exact_simulation_moments() {
+        let process = create_process();
+        let number_of_paths = 20000;
+
+        // A single step samples the exact Gaussian transition over the whole horizon
+        let rates: Vec<f64> = (0..number_of_paths).map(|_| process.generate_price_path(1)[0]).collect();
+        let mean = rates.iter().sum::<f64>() / number_of_paths as f64;
+        let variance = rates.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (number_of_paths - 1) as f64;
+
+        let decay = (-process.kappa * process.t).exp();
+        let expected_mean = process.theta + (process.r0 - process.theta) * decay;
+        let expected_variance = process.sigma.powi(2) / (2.0 * process.kappa) * (1.0 - decay.powi(2));
+        assert!((mean - expected_mean).abs() < 1e-3, "Mean {} too far from {}", mean, expected_mean);
+        assert!((variance / expected_variance - 1.0).abs() < 0.05, "Variance {} too far from {}", variance, expected_variance);
+    }
+
+    #[test]
+    fn test_simulated_discount_factors_match_bond_price() {
+        let process = create_process();
+        let number_of_paths = 5000;
+
+        let mean_discount_factor = (0..number_of_paths)
+            .map(|_| process.generate_price_path(200))
+            .map(|r_path| *process.discount_factor_path(&r_path).last().unwrap())
+            .sum::<f64>() / number_of_paths as f64;
+
+        let bond_price = process.zero_coupon_bond_price(0.0, process.t, process.r0);
+        assert!((mean_discount_factor - bond_price).abs() < 2e-3, "Mean discount factor {} too far from {}", mean_discount_factor, bond_price);
+    }
+
+    #[test]
+    fn test_bond_price_at_maturity_and_rate_sensitivity() {
+        let process = create_process();
+
+        assert!((process.zero_coupon_bond_price(1.0, 1.0, 0.05) - 1.0).abs() < 1e-15);
+        assert!(process.zero_coupon_bond_price(0.0, 1.0, 0.02) > process.zero_coupon_bond_price(0.0, 1.0, 0.04));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(VasicekProcess::try_new(0.03, 0.0, 0.06, 0.02, 2.0).err(), Some(ProcessError::NonPositiveParameter { name: "kappa", value: 0.0 }));
+        assert_eq!(VasicekProcess::try_new(0.03, 0.5, 0.06, -0.02, 2.0).err(), Some(ProcessError::NegativeVolatility { name: "sigma", value: -0.02 }));
+    }
+}
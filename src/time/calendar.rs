@@ -0,0 +1,386 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessDayConvention {
+    Unadjusted,
+    Following,
+    ModifiedFollowing,
+    Preceding,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    WeekendsOnly,
+    Target,
+    Nyse,
+    UsGovernmentBond, // SIFMA recommended closes of the US government securities market, on which SOFR fixes
+    London,
+    Tokyo,
+    Joint(Vec<Calendar>), // Holiday whenever any of the calendars is closed
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+// Anonymous Gregorian computus
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    ymd(year, month as u32, day as u32)
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).unwrap()
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5).unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+// US rule moving a Saturday holiday to Friday and a Sunday holiday to Monday
+fn observed_nearest_weekday(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+fn is_target_holiday(date: NaiveDate) -> bool {
+    let (year, month, day) = (date.year(), date.month(), date.day());
+    let easter = easter_sunday(year);
+
+    (month == 1 && day == 1)
+        || date == easter - Duration::days(2)
+        || date == easter + Duration::days(1)
+        || (month == 5 && day == 1)
+        || (month == 12 && (day == 25 || day == 26))
+        || (month == 12 && day == 31 && (year == 1999 || year == 2001))
+}
+
+fn is_nyse_holiday(date: NaiveDate) -> bool {
+    let year = date.year();
+    let easter = easter_sunday(year);
+
+    // New Year's Day falling on a Saturday is not observed on the preceding Friday
+    let new_years_day = ymd(year, 1, 1);
+    let new_year = if new_years_day.weekday() == Weekday::Sun { new_years_day + Duration::days(1) } else { new_years_day };
+
+    date == new_year
+        || (year >= 1998 && date == nth_weekday(year, 1, Weekday::Mon, 3))
+        || date == nth_weekday(year, 2, Weekday::Mon, 3)
+        || date == easter - Duration::days(2)
+        || date == last_weekday(year, 5, Weekday::Mon)
+        || (year >= 2022 && date == observed_nearest_weekday(ymd(year, 6, 19)))
+        || date == observed_nearest_weekday(ymd(year, 7, 4))
+        || date == nth_weekday(year, 9, Weekday::Mon, 1)
+        || date == nth_weekday(year, 11, Weekday::Thu, 4)
+        || date == observed_nearest_weekday(ymd(year, 12, 25))
+}
+
+// NYSE holidays plus Columbus Day and Veterans Day. Good Friday is taken as a full close, although SIFMA recommends
+// only an early close in some years.
+fn is_us_government_bond_holiday(date: NaiveDate) -> bool {
+    let year = date.year();
+
+    // Veterans Day falling on a Saturday is not observed on the preceding Friday
+    let veterans_day = ymd(year, 11, 11);
+    let veterans = if veterans_day.weekday() == Weekday::Sun { veterans_day + Duration::days(1) } else { veterans_day };
+
+    is_nyse_holiday(date) || date == nth_weekday(year, 10, Weekday::Mon, 2) || date == veterans
+}
+
+fn is_london_holiday(date: NaiveDate) -> bool {
+    let year = date.year();
+    let easter = easter_sunday(year);
+
+    // Weekend holidays roll to the following Monday, and to Tuesday when Christmas already takes the Monday
+    let new_year = match ymd(year, 1, 1).weekday() {
+        Weekday::Sat => ymd(year, 1, 3),
+        Weekday::Sun => ymd(year, 1, 2),
+        _ => ymd(year, 1, 1),
+    };
+    let (christmas, boxing_day) = match ymd(year, 12, 25).weekday() {
+        Weekday::Fri => (ymd(year, 12, 25), ymd(year, 12, 28)),
+        Weekday::Sat => (ymd(year, 12, 27), ymd(year, 12, 28)),
+        Weekday::Sun => (ymd(year, 12, 27), ymd(year, 12, 26)),
+        _ => (ymd(year, 12, 25), ymd(year, 12, 26)),
+    };
+
+    let early_may = match year {
+        1995 | 2020 => ymd(year, 5, 8),
+        _ => nth_weekday(year, 5, Weekday::Mon, 1),
+    };
+    let spring = match year {
+        2002 | 2012 => ymd(year, 6, 4),
+        2022 => ymd(year, 6, 2),
+        _ => last_weekday(year, 5, Weekday::Mon),
+    };
+    let special_holidays = [ymd(2002, 6, 3), ymd(2011, 4, 29), ymd(2012, 6, 5), ymd(2022, 6, 3), ymd(2022, 9, 19), ymd(2023, 5, 8)];
+
+    date == new_year
+        || date == easter - Duration::days(2)
+        || date == easter + Duration::days(1)
+        || date == early_may
+        || date == spring
+        || date == last_weekday(year, 8, Weekday::Mon)
+        || date == christmas
+        || date == boxing_day
+        || special_holidays.contains(&date)
+}
+
+// Equinox days from the approximation published for 1980-2099
+fn tokyo_equinoxes(year: i32) -> (NaiveDate, NaiveDate) {
+    let offset = 0.242194 * (year - 1980) as f64 - ((year - 1980) / 4) as f64;
+    let vernal = (20.8431 + offset).floor() as u32;
+    let autumnal = (23.2488 + offset).floor() as u32;
+
+    (ymd(year, 3, vernal), ymd(year, 9, autumnal))
+}
+
+fn is_japanese_national_holiday(date: NaiveDate) -> bool {
+    let (year, month, day) = (date.year(), date.month(), date.day());
+    let (vernal_equinox, autumnal_equinox) = tokyo_equinoxes(year);
+
+    let emperors_birthday = if year >= 2020 { Some(ymd(year, 2, 23)) } else if year <= 2018 { Some(ymd(year, 12, 23)) } else { None };
+    let marine_day = match year {
+        2020 => ymd(2020, 7, 23),
+        2021 => ymd(2021, 7, 22),
+        _ => nth_weekday(year, 7, Weekday::Mon, 3),
+    };
+    let mountain_day = match year {
+        2020 => Some(ymd(2020, 8, 10)),
+        2021 => Some(ymd(2021, 8, 8)),
+        _ if year >= 2016 => Some(ymd(year, 8, 11)),
+        _ => None,
+    };
+    let sports_day = match year {
+        2020 => ymd(2020, 7, 24),
+        2021 => ymd(2021, 7, 23),
+        _ => nth_weekday(year, 10, Weekday::Mon, 2),
+    };
+
+    (month == 1 && day == 1)
+        || date == nth_weekday(year, 1, Weekday::Mon, 2)
+        || (month == 2 && day == 11)
+        || Some(date) == emperors_birthday
+        || date == vernal_equinox
+        || (month == 4 && day == 29)
+        || (month == 5 && (3..=5).contains(&day))
+        || date == marine_day
+        || Some(date) == mountain_day
+        || date == nth_weekday(year, 9, Weekday::Mon, 3)
+        || date == autumnal_equinox
+        || date == sports_day
+        || (month == 11 && (day == 3 || day == 23))
+        || (year == 2019 && ((month == 4 && day == 30) || (month == 5 && (1..=2).contains(&day)) || (month == 10 && day == 22)))
+}
+
+fn is_tokyo_holiday(date: NaiveDate) -> bool {
+    let (month, day) = (date.month(), date.day());
+
+    // Banks close over the new year period in addition to the national holidays
+    if (month == 1 && day <= 3) || (month == 12 && day == 31) || is_japanese_national_holiday(date) {
+        return true;
+    }
+
+    // A holiday on a Sunday is substituted by the next day that is not already a holiday
+    let mut previous = date - Duration::days(1);
+    while is_japanese_national_holiday(previous) {
+        if previous.weekday() == Weekday::Sun {
+            return true;
+        }
+        previous -= Duration::days(1);
+    }
+
+    // A day sandwiched between two national holidays is a citizens' holiday
+    is_japanese_national_holiday(date - Duration::days(1)) && is_japanese_national_holiday(date + Duration::days(1))
+}
+
+impl Calendar {
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        match self {
+            Calendar::WeekendsOnly => is_weekend(date),
+            Calendar::Target => is_weekend(date) || is_target_holiday(date),
+            Calendar::Nyse => is_weekend(date) || is_nyse_holiday(date),
+            Calendar::UsGovernmentBond => is_weekend(date) || is_us_government_bond_holiday(date),
+            Calendar::London => is_weekend(date) || is_london_holiday(date),
+            Calendar::Tokyo => is_weekend(date) || is_tokyo_holiday(date),
+            Calendar::Joint(calendars) => calendars.iter().any(|calendar| calendar.is_holiday(date)),
+        }
+    }
+
+    pub fn is_business_day(&self, datetime: DateTime<Utc>) -> bool {
+        !self.is_holiday(datetime.date_naive())
+    }
+
+    pub fn adjust(&self, datetime: DateTime<Utc>, convention: BusinessDayConvention) -> DateTime<Utc> {
+        let step = |datetime: DateTime<Utc>, days: i64| {
+            let mut adjusted = datetime;
+            while !self.is_business_day(adjusted) {
+                adjusted += Duration::days(days);
+            }
+            adjusted
+        };
+
+        match convention {
+            BusinessDayConvention::Unadjusted => datetime,
+            BusinessDayConvention::Following => step(datetime, 1),
+            BusinessDayConvention::ModifiedFollowing => {
+                let following = step(datetime, 1);
+                if following.month() == datetime.month() { following } else { step(datetime, -1) }
+            }
+            BusinessDayConvention::Preceding => step(datetime, -1),
+        }
+    }
+
+    // Moves `business_days` good days forward (or backward when negative), starting from the adjusted date
+    pub fn advance(&self, datetime: DateTime<Utc>, business_days: i64) -> DateTime<Utc> {
+        let direction = business_days.signum();
+        let mut advanced = self.adjust(datetime, if direction < 0 { BusinessDayConvention::Preceding } else { BusinessDayConvention::Following });
+
+        for _ in 0..business_days.abs() {
+            advanced += Duration::days(direction);
+            while !self.is_business_day(advanced) {
+                advanced += Duration::days(direction);
+            }
+        }
+
+        advanced
+    }
+
+    // Settlement date `spot_lag` business days after a trade or exercise date, e.g. T+2
+    pub fn spot_date(&self, datetime: DateTime<Utc>, spot_lag: u32) -> DateTime<Utc> {
+        self.advance(datetime, spot_lag as i64)
+    }
+
+    // Business days in [start, end)
+    pub fn business_days_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+
+        start.date_naive().iter_days()
+            .take_while(|date| *date < end.date_naive())
+            .filter(|date| !self.is_holiday(*date))
+            .count() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::date;
+    use super::*;
+
+    #[test]
+    fn test_easter() {
+        assert_eq!(easter_sunday(2024), ymd(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), ymd(2025, 4, 20));
+        assert_eq!(easter_sunday(2000), ymd(2000, 4, 23));
+    }
+
+    #[test]
+    fn test_target_holidays() {
+        let calendar = Calendar::Target;
+        assert!(calendar.is_holiday(ymd(2024, 3, 29)));
+        assert!(calendar.is_holiday(ymd(2024, 4, 1)));
+        assert!(calendar.is_holiday(ymd(2024, 5, 1)));
+        assert!(calendar.is_holiday(ymd(2024, 12, 26)));
+        assert!(!calendar.is_holiday(ymd(2024, 5, 9)));
+    }
+
+    #[test]
+    fn test_nyse_holidays() {
+        let calendar = Calendar::Nyse;
+        assert!(calendar.is_holiday(ymd(2024, 1, 15)));
+        assert!(calendar.is_holiday(ymd(2024, 6, 19)));
+        assert!(calendar.is_holiday(ymd(2024, 11, 28)));
+        assert!(calendar.is_holiday(ymd(2021, 7, 5)));
+        assert!(calendar.is_holiday(ymd(2022, 12, 26)));
+        assert!(!calendar.is_holiday(ymd(2021, 12, 31)));
+        assert!(!calendar.is_holiday(ymd(2024, 4, 1)));
+    }
+
+    #[test]
+    fn test_us_government_bond_holidays() {
+        let calendar = Calendar::UsGovernmentBond;
+        assert!(calendar.is_holiday(ymd(2024, 10, 14)));
+        assert!(calendar.is_holiday(ymd(2024, 11, 11)));
+        assert!(calendar.is_holiday(ymd(2024, 7, 4)));
+        assert!(calendar.is_holiday(ymd(2021, 11, 11)));
+        assert!(!calendar.is_holiday(ymd(2023, 11, 10)));
+        assert!(!Calendar::Nyse.is_holiday(ymd(2024, 10, 14)));
+    }
+
+    #[test]
+    fn test_london_holidays() {
+        let calendar = Calendar::London;
+        assert!(calendar.is_holiday(ymd(2024, 5, 6)));
+        assert!(calendar.is_holiday(ymd(2024, 5, 27)));
+        assert!(calendar.is_holiday(ymd(2024, 8, 26)));
+        assert!(calendar.is_holiday(ymd(2022, 12, 27)));
+        assert!(calendar.is_holiday(ymd(2021, 12, 28)));
+        assert!(calendar.is_holiday(ymd(2022, 9, 19)));
+    }
+
+    #[test]
+    fn test_tokyo_holidays() {
+        let calendar = Calendar::Tokyo;
+        assert!(calendar.is_holiday(ymd(2024, 1, 2)));
+        assert!(calendar.is_holiday(ymd(2024, 3, 20)));
+        assert!(calendar.is_holiday(ymd(2024, 9, 23)));
+        // Substitute holidays for Constitution Day week ending on a Sunday and for Culture Day on a Sunday
+        assert!(calendar.is_holiday(ymd(2024, 5, 6)));
+        assert!(calendar.is_holiday(ymd(2024, 11, 4)));
+        // Citizens' holiday between Respect for the Aged Day and the autumnal equinox
+        assert!(calendar.is_holiday(ymd(2015, 9, 22)));
+        assert!(!calendar.is_holiday(ymd(2024, 9, 24)));
+    }
+
+    #[test]
+    fn test_joint_calendar() {
+        let calendar = Calendar::Joint(vec![Calendar::Target, Calendar::Nyse]);
+        assert!(calendar.is_holiday(ymd(2024, 7, 4)));
+        assert!(calendar.is_holiday(ymd(2024, 5, 1)));
+        assert!(!calendar.is_holiday(ymd(2024, 7, 5)));
+    }
+
+    #[test]
+    fn test_adjustment_conventions() {
+        let calendar = Calendar::Target;
+        let saturday = date(2024, 8, 31);
+
+        assert_eq!(calendar.adjust(saturday, BusinessDayConvention::Unadjusted), saturday);
+        assert_eq!(calendar.adjust(saturday, BusinessDayConvention::Following), date(2024, 9, 2));
+        assert_eq!(calendar.adjust(saturday, BusinessDayConvention::ModifiedFollowing), date(2024, 8, 30));
+        assert_eq!(calendar.adjust(saturday, BusinessDayConvention::Preceding), date(2024, 8, 30));
+        assert_eq!(calendar.adjust(date(2024, 3, 29), BusinessDayConvention::ModifiedFollowing), date(2024, 3, 28));
+    }
+
+    #[test]
+    fn test_spot_date() {
+        // T+2 from the Thursday before Easter skips Good Friday and Easter Monday
+        assert_eq!(Calendar::Target.spot_date(date(2024, 3, 28), 2), date(2024, 4, 3));
+        assert_eq!(Calendar::Nyse.spot_date(date(2024, 3, 28), 2), date(2024, 4, 2));
+        assert_eq!(Calendar::Target.advance(date(2024, 4, 3), -2), date(2024, 3, 28));
+        assert_eq!(Calendar::Target.business_days_between(date(2024, 3, 28), date(2024, 4, 3)), 2);
+    }
+}
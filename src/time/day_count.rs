@@ -0,0 +1,241 @@
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+
+use crate::time::Calendar;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayCount {
+    Actual360,
+    Actual365Fixed,
+    ActualActualIsda,
+    ActualActualIcma,
+    Thirty360Us,
+    ThirtyE360,
+    Business252,
+}
+
+fn is_leap_year(year: i32) -> bool {
+    NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+}
+
+fn days_in_year(year: i32) -> f64 {
+    if is_leap_year(year) { 366.0 } else { 365.0 }
+}
+
+fn is_last_day_of_february(date: NaiveDate) -> bool {
+    date.month() == 2 && date.succ_opt().is_some_and(|next| next.month() == 3)
+}
+
+fn thirty_360_days(start: NaiveDate, end: NaiveDate, day_count: DayCount) -> i64 {
+    let (mut d1, mut d2) = (start.day() as i64, end.day() as i64);
+
+    match day_count {
+        DayCount::Thirty360Us => {
+            if is_last_day_of_february(start) && is_last_day_of_february(end) {
+                d2 = 30;
+            }
+            if is_last_day_of_february(start) {
+                d1 = 30;
+            }
+            if d2 == 31 && d1 >= 30 {
+                d2 = 30;
+            }
+            if d1 == 31 {
+                d1 = 30;
+            }
+        }
+        _ => {
+            d1 = d1.min(30);
+            d2 = d2.min(30);
+        }
+    }
+
+    360 * (end.year() - start.year()) as i64 + 30 * (end.month() as i64 - start.month() as i64) + d2 - d1
+}
+
+fn actual_actual_isda(start: NaiveDate, end: NaiveDate) -> f64 {
+    if start.year() == end.year() {
+        return (end - start).num_days() as f64 / days_in_year(start.year());
+    }
+
+    let start_of_next_year = NaiveDate::from_ymd_opt(start.year() + 1, 1, 1).unwrap();
+    let start_of_end_year = NaiveDate::from_ymd_opt(end.year(), 1, 1).unwrap();
+
+    (start_of_next_year - start).num_days() as f64 / days_in_year(start.year())
+        + (end.year() - start.year() - 1) as f64
+        + (end - start_of_end_year).num_days() as f64 / days_in_year(end.year())
+}
+
+// Act/Act ICMA over whole reference periods of 12 / frequency months, counted back from the end date
+fn actual_actual_icma(start: NaiveDate, end: NaiveDate, frequency: u32) -> f64 {
+    let months = Months::new(12 / frequency.max(1));
+    let mut year_fraction = 0.0;
+    let mut period_end = end;
+
+    loop {
+        let period_start = period_end.checked_sub_months(months).unwrap();
+        if period_start <= start {
+            let period_days = (period_end - period_start).num_days() as f64;
+            return year_fraction + (period_end - start).num_days() as f64 / (frequency as f64 * period_days);
+        }
+        year_fraction += 1.0 / frequency as f64;
+        period_end = period_start;
+    }
+}
+
+// Act/Act ICMA of an accrual period against its regular reference period. Long stubs reaching beyond the reference
+// period are split into the notional regular periods before or after it, each counted against its own length.
+fn actual_actual_icma_with_reference(start: NaiveDate, end: NaiveDate, reference_start: NaiveDate, reference_end: NaiveDate, frequency: u32) -> f64 {
+    let months = Months::new(12 / frequency.max(1));
+    let fraction_of_period = |from: NaiveDate, to: NaiveDate, period_start: NaiveDate, period_end: NaiveDate| {
+        (to - from).num_days().max(0) as f64 / (frequency as f64 * (period_end - period_start).num_days() as f64)
+    };
+
+    let mut year_fraction = fraction_of_period(start.max(reference_start), end.min(reference_end), reference_start, reference_end);
+
+    let mut period_end = reference_start;
+    while start < period_end {
+        let period_start = period_end.checked_sub_months(months).unwrap();
+        year_fraction += fraction_of_period(start.max(period_start), period_end, period_start, period_end);
+        period_end = period_start;
+    }
+
+    let mut period_start = reference_end;
+    while end > period_start {
+        let period_end = period_start.checked_add_months(months).unwrap();
+        year_fraction += fraction_of_period(period_start, end.min(period_end), period_start, period_end);
+        period_start = period_end;
+    }
+
+    year_fraction
+}
+
+impl DayCount {
+    // Business/252 counts weekdays only; use `day_count_on_calendar` to also skip holidays
+    pub fn day_count(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        self.day_count_on_calendar(start, end, &Calendar::WeekendsOnly)
+    }
+
+    pub fn day_count_on_calendar(&self, start: DateTime<Utc>, end: DateTime<Utc>, calendar: &Calendar) -> i64 {
+        if let DayCount::Business252 = self {
+            return calendar.business_days_between(start, end);
+        }
+
+        let (start, end) = (start.date_naive(), end.date_naive());
+        match self {
+            DayCount::Thirty360Us | DayCount::ThirtyE360 => thirty_360_days(start, end, *self),
+            _ => (end - start).num_days(),
+        }
+    }
+
+    pub fn year_fraction(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        self.year_fraction_on_calendar(start, end, &Calendar::WeekendsOnly)
+    }
+
+    pub fn year_fraction_on_calendar(&self, start: DateTime<Utc>, end: DateTime<Utc>, calendar: &Calendar) -> f64 {
+        if end < start {
+            return -self.year_fraction_on_calendar(end, start, calendar);
+        }
+
+        match self {
+            DayCount::Actual360 => self.day_count(start, end) as f64 / 360.0,
+            DayCount::Actual365Fixed => self.day_count(start, end) as f64 / 365.0,
+            DayCount::ActualActualIsda => actual_actual_isda(start.date_naive(), end.date_naive()),
+            DayCount::ActualActualIcma => actual_actual_icma(start.date_naive(), end.date_naive(), 1),
+            DayCount::Thirty360Us | DayCount::ThirtyE360 => self.day_count(start, end) as f64 / 360.0,
+            DayCount::Business252 => self.day_count_on_calendar(start, end, calendar) as f64 / 252.0,
+        }
+    }
+
+    // Year fraction of an accrual period inside a coupon schedule paying `frequency` times a year. Only Act/Act ICMA
+    // depends on the reference period; the other conventions ignore it.
+    pub fn year_fraction_with_reference(&self, start: DateTime<Utc>, end: DateTime<Utc>, reference_start: DateTime<Utc>, reference_end: DateTime<Utc>, frequency: u32) -> f64 {
+        match self {
+            DayCount::ActualActualIcma => actual_actual_icma_with_reference(start.date_naive(), end.date_naive(), reference_start.date_naive(), reference_end.date_naive(), frequency),
+            _ => self.year_fraction(start, end),
+        }
+    }
+}
+
+impl fmt::Display for DayCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            DayCount::Actual360 => "ACT/360",
+            DayCount::Actual365Fixed => "ACT/365F",
+            DayCount::ActualActualIsda => "ACT/ACT ISDA",
+            DayCount::ActualActualIcma => "ACT/ACT ICMA",
+            DayCount::Thirty360Us => "30/360 US",
+            DayCount::ThirtyE360 => "30E/360",
+            DayCount::Business252 => "BUS/252",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::date;
+    use super::*;
+
+    #[test]
+    fn test_actual_conventions() {
+        let (start, end) = (date(2023, 1, 1), date(2024, 1, 1));
+        assert_eq!(DayCount::Actual360.year_fraction(start, end), 365.0 / 360.0);
+        assert_eq!(DayCount::Actual365Fixed.year_fraction(start, end), 1.0);
+        assert_eq!(DayCount::Actual365Fixed.year_fraction(end, start), -1.0);
+    }
+
+    #[test]
+    fn test_actual_actual_isda_across_leap_year() {
+        let year_fraction = DayCount::ActualActualIsda.year_fraction(date(2023, 12, 1), date(2024, 3, 1));
+        assert!((year_fraction - (31.0 / 365.0 + 60.0 / 366.0)).abs() < 1e-15);
+        assert_eq!(DayCount::ActualActualIsda.year_fraction(date(2020, 1, 1), date(2023, 1, 1)), 3.0);
+    }
+
+    #[test]
+    fn test_actual_actual_icma() {
+        let (start, end) = (date(2024, 2, 15), date(2024, 8, 15));
+        assert_eq!(DayCount::ActualActualIcma.year_fraction_with_reference(start, end, start, end, 2), 0.5);
+
+        // Short first coupon accruing 92 days of a 182 day semi-annual reference period
+        let short = DayCount::ActualActualIcma.year_fraction_with_reference(date(2024, 5, 15), end, start, end, 2);
+        assert!((short - 92.0 / 364.0).abs() < 1e-15);
+
+        assert_eq!(DayCount::ActualActualIcma.year_fraction(date(2021, 3, 10), date(2024, 3, 10)), 3.0);
+    }
+
+    #[test]
+    fn test_actual_actual_icma_long_stubs() {
+        let (reference_start, reference_end) = (date(2024, 2, 15), date(2024, 8, 15));
+
+        // Long first coupon: 76 days of the 184 day notional period before the reference period, then a full period
+        let long_first = DayCount::ActualActualIcma.year_fraction_with_reference(date(2023, 12, 1), reference_end, reference_start, reference_end, 2);
+        assert!((long_first - (76.0 / 368.0 + 0.5)).abs() < 1e-15);
+
+        // Long final coupon: a full period, then 31 days of the 184 day notional period after it
+        let long_final = DayCount::ActualActualIcma.year_fraction_with_reference(reference_start, date(2024, 9, 15), reference_start, reference_end, 2);
+        assert!((long_final - (0.5 + 31.0 / 368.0)).abs() < 1e-15);
+
+        // Stubs longer than two periods span several notional periods
+        let very_long = DayCount::ActualActualIcma.year_fraction_with_reference(date(2023, 2, 15), reference_end, reference_start, reference_end, 2);
+        assert!((very_long - 1.5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_thirty_360_conventions() {
+        assert_eq!(DayCount::Thirty360Us.day_count(date(2024, 1, 31), date(2024, 3, 31)), 60);
+        assert_eq!(DayCount::Thirty360Us.day_count(date(2024, 1, 15), date(2024, 3, 31)), 76);
+        assert_eq!(DayCount::Thirty360Us.day_count(date(2023, 2, 28), date(2023, 8, 31)), 180);
+        assert_eq!(DayCount::ThirtyE360.day_count(date(2024, 1, 15), date(2024, 3, 31)), 75);
+        assert_eq!(DayCount::ThirtyE360.day_count(date(2023, 2, 28), date(2023, 8, 31)), 182);
+    }
+
+    #[test]
+    fn test_business_252() {
+        // Monday to the following Monday
+        assert_eq!(DayCount::Business252.day_count(date(2024, 1, 1), date(2024, 1, 8)), 5);
+        assert_eq!(DayCount::Business252.year_fraction(date(2024, 1, 1), date(2024, 1, 8)), 5.0 / 252.0);
+        assert_eq!(DayCount::Business252.day_count_on_calendar(date(2024, 1, 1), date(2024, 1, 8), &Calendar::Target), 4);
+    }
+}
@@ -0,0 +1,14 @@
+pub mod calendar;
+pub use self::calendar::{BusinessDayConvention, Calendar};
+pub mod day_count;
+pub use self::day_count::DayCount;
+pub mod schedule;
+pub use self::schedule::{AccrualPeriod, Frequency, Schedule, StubType};
+
+// Midnight UTC on the given calendar date, shared by the test modules
+#[cfg(test)]
+pub(crate) fn date(year: i32, month: u32, day: u32) -> chrono::DateTime<chrono::Utc> {
+    use chrono::TimeZone;
+
+    chrono::Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+}
@@ -0,0 +1,262 @@
+use chrono::{DateTime, Datelike, Months, Utc};
+
+use crate::error::QuantError;
+use crate::time::{BusinessDayConvention, Calendar, DayCount};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Annual,
+    SemiAnnual,
+    Quarterly,
+    Monthly,
+}
+
+impl Frequency {
+    pub fn months(&self) -> u32 {
+        12 / self.periods_per_year()
+    }
+
+    pub fn periods_per_year(&self) -> u32 {
+        match self {
+            Frequency::Annual => 1,
+            Frequency::SemiAnnual => 2,
+            Frequency::Quarterly => 4,
+            Frequency::Monthly => 12,
+        }
+    }
+}
+
+// Where the irregular period goes when the dates do not divide into whole periods
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StubType {
+    ShortFront,
+    LongFront,
+    ShortBack,
+    LongBack,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccrualPeriod {
+    pub accrual_start: DateTime<Utc>,
+    pub accrual_end: DateTime<Utc>,
+    pub payment_datetime: DateTime<Utc>,
+    pub reference_start: DateTime<Utc>, // Regular period the accrual belongs to, differs from the accrual dates for stubs
+    pub reference_end: DateTime<Utc>,
+}
+
+impl AccrualPeriod {
+    pub fn year_fraction(&self, day_count: DayCount, frequency: Frequency) -> f64 {
+        day_count.year_fraction_with_reference(self.accrual_start, self.accrual_end, self.reference_start, self.reference_end, frequency.periods_per_year())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    pub start_datetime: DateTime<Utc>,
+    pub end_datetime: DateTime<Utc>,
+    pub frequency: Frequency,
+    pub stub_type: StubType,
+    pub roll_convention: BusinessDayConvention, // Adjustment of the accrual dates
+    pub end_of_month: bool, // Roll on month ends when the anchor date is a month end
+    pub calendar: Calendar,
+    pub payment_lag: u32, // Business days between accrual end and payment
+}
+
+fn is_end_of_month(datetime: DateTime<Utc>) -> bool {
+    datetime.date_naive().succ_opt().map(|next| next.month()) != Some(datetime.month())
+}
+
+fn end_of_month(datetime: DateTime<Utc>) -> DateTime<Utc> {
+    let mut rolled = datetime;
+    while !is_end_of_month(rolled) {
+        rolled += chrono::Duration::days(1);
+    }
+    rolled
+}
+
+impl Schedule {
+    pub fn new(start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, frequency: Frequency, calendar: Calendar) -> Self {
+        Schedule {
+            start_datetime,
+            end_datetime,
+            frequency,
+            stub_type: StubType::ShortFront,
+            roll_convention: BusinessDayConvention::ModifiedFollowing,
+            end_of_month: false,
+            calendar,
+            payment_lag: 0,
+        }
+    }
+
+    pub fn try_new(start_datetime: DateTime<Utc>, end_datetime: DateTime<Utc>, frequency: Frequency, calendar: Calendar) -> Result<Self, QuantError> {
+        if start_datetime >= end_datetime {
+            return Err(QuantError::InvalidDateRange { start: start_datetime, end: end_datetime });
+        }
+        Ok(Schedule::new(start_datetime, end_datetime, frequency, calendar))
+    }
+
+    pub fn with_stub_type(mut self, stub_type: StubType) -> Self {
+        self.stub_type = stub_type;
+        self
+    }
+
+    pub fn with_roll_convention(mut self, roll_convention: BusinessDayConvention) -> Self {
+        self.roll_convention = roll_convention;
+        self
+    }
+
+    pub fn with_end_of_month(mut self, end_of_month: bool) -> Self {
+        self.end_of_month = end_of_month;
+        self
+    }
+
+    pub fn with_payment_lag(mut self, payment_lag: u32) -> Self {
+        self.payment_lag = payment_lag;
+        self
+    }
+
+    fn backward(&self) -> bool {
+        matches!(self.stub_type, StubType::ShortFront | StubType::LongFront)
+    }
+
+    // Rolls `periods` whole periods away from the anchor date, forwards from the start or backwards from the end
+    fn roll(&self, periods: u32) -> DateTime<Utc> {
+        let months = Months::new(periods * self.frequency.months());
+        let (anchor, rolled) = if self.backward() {
+            (self.end_datetime, self.end_datetime.checked_sub_months(months).unwrap())
+        } else {
+            (self.start_datetime, self.start_datetime.checked_add_months(months).unwrap())
+        };
+
+        if self.end_of_month && is_end_of_month(anchor) { end_of_month(rolled) } else { rolled }
+    }
+
+    // Unadjusted period boundaries from start to end, with the stub already merged into its neighbour for long stubs.
+    // Empty unless the start is before the end.
+    pub fn unadjusted_dates(&self) -> Vec<DateTime<Utc>> {
+        if self.start_datetime >= self.end_datetime {
+            return Vec::new();
+        }
+
+        let (first, last) = if self.backward() { (self.end_datetime, self.start_datetime) } else { (self.start_datetime, self.end_datetime) };
+        let beyond = |date: DateTime<Utc>| if self.backward() { date <= last } else { date >= last };
+
+        let mut dates = vec![first];
+        let mut periods = 1;
+        loop {
+            let date = self.roll(periods);
+            if beyond(date) {
+                break;
+            }
+            dates.push(date);
+            periods += 1;
+        }
+
+        let stub = self.roll(periods) != last;
+        if stub && dates.len() > 1 && matches!(self.stub_type, StubType::LongFront | StubType::LongBack) {
+            dates.pop();
+        }
+        dates.push(last);
+
+        if self.backward() {
+            dates.reverse();
+        }
+        dates
+    }
+
+    pub fn periods(&self) -> Vec<AccrualPeriod> {
+        let dates = self.unadjusted_dates();
+        let months = Months::new(self.frequency.months());
+        let last = dates.len().saturating_sub(2);
+
+        dates.windows(2).enumerate()
+            .map(|(i, window)| {
+                let (start, end) = (window[0], window[1]);
+                let (reference_start, reference_end) = match self.stub_type {
+                    StubType::ShortFront | StubType::LongFront if i == 0 => (end.checked_sub_months(months).unwrap(), end),
+                    StubType::ShortBack | StubType::LongBack if i == last => (start, start.checked_add_months(months).unwrap()),
+                    _ => (start, end),
+                };
+
+                let accrual_start = self.calendar.adjust(start, self.roll_convention);
+                let accrual_end = self.calendar.adjust(end, self.roll_convention);
+                let payment_datetime = self.calendar.advance(accrual_end, self.payment_lag as i64);
+
+                AccrualPeriod { accrual_start, accrual_end, payment_datetime, reference_start, reference_end }
+            })
+            .collect()
+    }
+
+    pub fn payment_dates(&self) -> Vec<DateTime<Utc>> {
+        self.periods().iter().map(|period| period.payment_datetime).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::date;
+    use super::*;
+
+    #[test]
+    fn test_regular_schedule() {
+        let schedule = Schedule::new(date(2024, 1, 15), date(2025, 1, 15), Frequency::Quarterly, Calendar::Target);
+
+        assert_eq!(schedule.unadjusted_dates(), vec![date(2024, 1, 15), date(2024, 4, 15), date(2024, 7, 15), date(2024, 10, 15), date(2025, 1, 15)]);
+        let periods = schedule.periods();
+        assert_eq!(periods.len(), 4);
+        assert_eq!((periods[0].reference_start, periods[0].reference_end), (date(2024, 1, 15), date(2024, 4, 15)));
+        assert_eq!(periods[0].year_fraction(DayCount::ActualActualIcma, Frequency::Quarterly), 0.25);
+    }
+
+    #[test]
+    fn test_stub_types() {
+        let (start, end) = (date(2024, 3, 10), date(2025, 6, 15));
+        let schedule = |stub_type| Schedule::new(start, end, Frequency::SemiAnnual, Calendar::WeekendsOnly)
+            .with_roll_convention(BusinessDayConvention::Unadjusted)
+            .with_stub_type(stub_type);
+
+        assert_eq!(schedule(StubType::ShortFront).unadjusted_dates(), vec![start, date(2024, 6, 15), date(2024, 12, 15), end]);
+        assert_eq!(schedule(StubType::LongFront).unadjusted_dates(), vec![start, date(2024, 12, 15), end]);
+        assert_eq!(schedule(StubType::ShortBack).unadjusted_dates(), vec![start, date(2024, 9, 10), date(2025, 3, 10), end]);
+        assert_eq!(schedule(StubType::LongBack).unadjusted_dates(), vec![start, date(2024, 9, 10), end]);
+
+        let front_stub = &schedule(StubType::ShortFront).periods()[0];
+        assert_eq!((front_stub.reference_start, front_stub.reference_end), (date(2023, 12, 15), date(2024, 6, 15)));
+    }
+
+    #[test]
+    fn test_end_of_month_rule() {
+        let (start, end) = (date(2024, 2, 29), date(2025, 2, 28));
+        let dates = Schedule::new(start, end, Frequency::Quarterly, Calendar::WeekendsOnly)
+            .with_roll_convention(BusinessDayConvention::Unadjusted)
+            .with_stub_type(StubType::ShortBack)
+            .with_end_of_month(true)
+            .unadjusted_dates();
+
+        assert_eq!(dates, vec![start, date(2024, 5, 31), date(2024, 8, 31), date(2024, 11, 30), end]);
+    }
+
+    #[test]
+    fn test_adjusted_accrual_and_payment_dates() {
+        let periods = Schedule::new(date(2024, 2, 29), date(2024, 8, 31), Frequency::Quarterly, Calendar::Target)
+            .with_payment_lag(2)
+            .periods();
+
+        // 31 August 2024 is a Saturday and rolls back under Modified Following
+        assert_eq!(periods[1].accrual_end, date(2024, 8, 30));
+        assert_eq!(periods[1].payment_datetime, date(2024, 9, 3));
+        assert_eq!(periods[0].accrual_start, date(2024, 2, 29));
+    }
+
+    #[test]
+    fn test_invalid_date_range() {
+        let (start, end) = (date(2025, 1, 15), date(2024, 1, 15));
+        assert_eq!(Schedule::try_new(start, end, Frequency::Annual, Calendar::Target).unwrap_err(), QuantError::InvalidDateRange { start, end });
+        assert!(Schedule::try_new(start, start, Frequency::Annual, Calendar::Target).is_err());
+        assert!(Schedule::try_new(end, start, Frequency::Annual, Calendar::Target).is_ok());
+
+        // Unvalidated schedules over an empty range have no periods rather than inverted ones
+        assert!(Schedule::new(start, end, Frequency::Annual, Calendar::Target).periods().is_empty());
+        assert!(Schedule::new(start, start, Frequency::Annual, Calendar::Target).payment_dates().is_empty());
+    }
+}